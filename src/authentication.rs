@@ -1,17 +1,31 @@
-use reqwest::{Client, Url};
+use crate::{
+    error::{AuthenticationError, Error},
+    retry::{self, RateLimiter, RetryPolicy},
+};
+use client_assertion::{ClientAssertion, PrivateKeyJwt};
+use reqwest::{Client, RequestBuilder, Response, Url};
+use serde::{Deserialize, Serialize};
+use user_profile::UserInfoCache;
 
 pub mod authorize_application;
+pub mod backchannel_logout;
 pub mod change_password;
+pub mod ciba;
+pub mod client_assertion;
 pub mod device_code;
 pub mod dynamic_client_registration;
 pub mod get_token;
+pub mod id_token;
 pub mod login;
 pub mod logout;
 pub mod mfa;
 pub mod passwordless;
+pub mod pushed_authorization_request;
 pub mod revoke_refresh_token;
 pub mod saml;
 pub mod signup;
+#[cfg(test)]
+pub(crate) mod test_keys;
 pub mod user_profile;
 pub mod ws_federation;
 
@@ -19,12 +33,17 @@ pub enum AuthenticationMethod {
     OAuth2Token(String),
     ClientIDClientSecret(String, String),
     ClientID(String),
+    PrivateKeyJwt(PrivateKeyJwt),
 }
 
 pub struct Api {
     pub base_url: Url,
     pub authentication: AuthenticationMethod,
     client: Client,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    token_endpoint_auth_method: TokenEndpointAuthMethod,
+    user_info_cache: Option<UserInfoCache>,
 }
 
 impl Api {
@@ -33,9 +52,120 @@ impl Api {
             base_url,
             authentication,
             client: Api::build_client(),
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: None,
+            token_endpoint_auth_method: TokenEndpointAuthMethod::default(),
+            user_info_cache: None,
+        }
+    }
+
+    /// Caches `/userinfo` responses fetched with [`Api::get_user_info`] and [`Api::user_info_as`].
+    pub fn with_user_info_cache(mut self, user_info_cache: UserInfoCache) -> Self {
+        self.user_info_cache = Some(user_info_cache);
+        self
+    }
+
+    /// Sets how a [`AuthenticationMethod::ClientIDClientSecret`] secret is sent.
+    pub fn with_token_endpoint_auth_method(
+        mut self,
+        token_endpoint_auth_method: TokenEndpointAuthMethod,
+    ) -> Self {
+        self.token_endpoint_auth_method = token_endpoint_auth_method;
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Sends a request built by this client, applying its retry policy and rate limiter.
+    ///
+    /// Non-2xx responses are decoded into [`Error::AuthenticationError`]; endpoints that
+    /// redirect (e.g. `/authorize`) should be sent without following redirects.
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response, Error> {
+        let response = retry::send(
+            &self.client,
+            request,
+            &self.retry_policy,
+            self.rate_limiter.as_ref(),
+        )
+        .await?;
+        if response.status().is_success() {
+            return Ok(response);
+        }
+        let status = response.status();
+        let body = response.text().await?;
+        Err(AuthenticationError::from_body(status, &body).into())
+    }
+
+    /// The `client_id` of the configured [`AuthenticationMethod`], if any.
+    pub fn client_id(&self) -> Option<&str> {
+        match &self.authentication {
+            AuthenticationMethod::OAuth2Token(_) => None,
+            AuthenticationMethod::ClientIDClientSecret(client_id, _)
+            | AuthenticationMethod::ClientID(client_id) => Some(client_id),
+            AuthenticationMethod::PrivateKeyJwt(private_key_jwt) => {
+                Some(private_key_jwt.client_id())
+            }
         }
     }
 
+    /// Authenticates the client as configured on this client, unless the request carries its
+    /// own credentials: a `client_secret`, or a `client_id` other than the configured one.
+    pub(crate) fn client_authentication<T: Serialize + ClientCredentials>(
+        &self,
+        request: T,
+    ) -> ClientAuthenticationParameters<'_, T> {
+        let mut parameters = ClientAuthenticationParameters {
+            client_id: None,
+            request,
+            client_secret: None,
+            client_assertion: None,
+            basic_auth: None,
+        };
+        let client_id = match self.client_id() {
+            Some(client_id) => client_id,
+            None => return parameters,
+        };
+        let (requested_id, requested_secret) = parameters.request.client_credentials();
+        match requested_id {
+            Some(requested_id) if requested_id != client_id => return parameters,
+            Some(_) => {}
+            None => parameters.client_id = Some(client_id.to_string()),
+        }
+        if requested_secret.is_some() {
+            return parameters;
+        }
+
+        match &self.authentication {
+            AuthenticationMethod::ClientIDClientSecret(client_id, client_secret) => {
+                match self.token_endpoint_auth_method {
+                    TokenEndpointAuthMethod::ClientSecretPost => {
+                        parameters.client_secret = Some(client_secret.clone())
+                    }
+                    TokenEndpointAuthMethod::ClientSecretBasic => {
+                        parameters.basic_auth = Some((client_id.clone(), client_secret.clone()))
+                    }
+                    TokenEndpointAuthMethod::None => {}
+                }
+            }
+            AuthenticationMethod::PrivateKeyJwt(private_key_jwt) => {
+                parameters.client_assertion = Some(ClientAssertion::new(
+                    private_key_jwt,
+                    self.base_url.as_str(),
+                ))
+            }
+            _ => {}
+        }
+        parameters
+    }
+
     fn build_client() -> Client {
         static USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
         reqwest::Client::builder()
@@ -45,7 +175,88 @@ impl Api {
     }
 }
 
+/// How the client secret is sent, matching the application's `token_endpoint_auth_method`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenEndpointAuthMethod {
+    /// The application is public and has no secret.
+    None,
+    /// In the request body.
+    #[default]
+    ClientSecretPost,
+    /// As HTTP Basic authentication.
+    ClientSecretBasic,
+}
+
+/// Client credentials a request carries itself, which take precedence over the configured
+/// [`AuthenticationMethod`].
+pub(crate) trait ClientCredentials {
+    /// The request's `client_id` and `client_secret`.
+    fn client_credentials(&self) -> (Option<&str>, Option<&str>);
+}
+
+impl<T: ClientCredentials> ClientCredentials for &T {
+    fn client_credentials(&self) -> (Option<&str>, Option<&str>) {
+        (*self).client_credentials()
+    }
+}
+
+/// Request parameters along with the client authentication added by
+/// [`Api::client_authentication`].
+#[derive(Serialize)]
+pub(crate) struct ClientAuthenticationParameters<'a, T> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_id: Option<String>,
+    #[serde(flatten)]
+    request: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_secret: Option<String>,
+    #[serde(flatten)]
+    client_assertion: Option<ClientAssertion<'a>>,
+    #[serde(skip)]
+    basic_auth: Option<(String, String)>,
+}
+
+// With HTTP Basic authentication the body carries no `client_id`, since a client must not use
+// more than one authentication method per request (RFC 6749 §2.3). Bodies that fail to
+// serialize are passed on as they are, so that reqwest reports the error when building.
+impl<T: Serialize> ClientAuthenticationParameters<'_, T> {
+    pub(crate) fn form(&self, request: RequestBuilder) -> RequestBuilder {
+        let (client_id, client_secret) = match &self.basic_auth {
+            Some(basic_auth) => basic_auth,
+            None => return request.form(self),
+        };
+        let request = request.basic_auth(client_id, Some(client_secret));
+        let pairs = serde_urlencoded::to_string(self)
+            .ok()
+            .and_then(|body| serde_urlencoded::from_str::<Vec<(String, String)>>(&body).ok());
+        match pairs {
+            Some(mut pairs) => {
+                pairs.retain(|(name, _)| name != "client_id");
+                request.form(&pairs)
+            }
+            None => request.form(self),
+        }
+    }
+
+    pub(crate) fn json(&self, request: RequestBuilder) -> RequestBuilder {
+        let (client_id, client_secret) = match &self.basic_auth {
+            Some(basic_auth) => basic_auth,
+            None => return request.json(self),
+        };
+        let request = request.basic_auth(client_id, Some(client_secret));
+        match serde_json::to_value(self) {
+            Ok(serde_json::Value::Object(mut body)) => {
+                body.remove("client_id");
+                request.json(&body)
+            }
+            _ => request.json(self),
+        }
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
@@ -65,4 +276,107 @@ mod tests {
         assert_eq!(request.headers().len(), 0);
         assert_eq!(request.body().is_none(), true);
     }
+
+    fn client_credentials_build_request(
+        api: &Api,
+        client_id: Option<String>,
+        client_secret: Option<String>,
+    ) -> reqwest::Request {
+        use get_token::{client_credentials_flow, GetToken};
+        let parameters = client_credentials_flow::RequestParameters {
+            client_id,
+            client_secret,
+            audience: String::from("some_awesome_audience"),
+        };
+        api.client_credentials_flow(parameters).build().unwrap()
+    }
+
+    fn some_awesome_client_credentials() -> AuthenticationMethod {
+        AuthenticationMethod::ClientIDClientSecret(
+            String::from("some_awesome_client_id"),
+            String::from("some_awesome_client_secret"),
+        )
+    }
+
+    #[test]
+    fn client_secret_post_build_request() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let api = Api::init(base_url, some_awesome_client_credentials());
+        let request = client_credentials_build_request(&api, None, None);
+        let test_body = String::from(
+            "client_id=some_awesome_client_id&\
+            grant_type=client_credentials&\
+            audience=some_awesome_audience&\
+            client_secret=some_awesome_client_secret",
+        );
+        assert!(request.headers().get("authorization").is_none());
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[test]
+    fn client_secret_basic_build_request() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let api = Api::init(base_url, some_awesome_client_credentials())
+            .with_token_endpoint_auth_method(TokenEndpointAuthMethod::ClientSecretBasic);
+        let request = client_credentials_build_request(&api, None, None);
+        let test_body = String::from(
+            "grant_type=client_credentials&\
+            audience=some_awesome_audience",
+        );
+        assert_eq!(
+            request.headers()["authorization"],
+            "Basic c29tZV9hd2Vzb21lX2NsaWVudF9pZDpzb21lX2F3ZXNvbWVfY2xpZW50X3NlY3JldA==",
+        );
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[test]
+    fn client_secret_basic_omits_requested_client_id_build_request() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let api = Api::init(base_url, some_awesome_client_credentials())
+            .with_token_endpoint_auth_method(TokenEndpointAuthMethod::ClientSecretBasic);
+        let request = client_credentials_build_request(
+            &api,
+            Some(String::from("some_awesome_client_id")),
+            None,
+        );
+        let test_body = String::from(
+            "grant_type=client_credentials&\
+            audience=some_awesome_audience",
+        );
+        assert!(request.headers().get("authorization").is_some());
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[test]
+    fn client_authentication_override_build_request() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let api = Api::init(base_url, some_awesome_client_credentials())
+            .with_token_endpoint_auth_method(TokenEndpointAuthMethod::ClientSecretBasic);
+        let request = client_credentials_build_request(
+            &api,
+            Some(String::from("some_other_client_id")),
+            Some(String::from("some_other_client_secret")),
+        );
+        let test_body = String::from(
+            "grant_type=client_credentials&\
+            client_id=some_other_client_id&\
+            client_secret=some_other_client_secret&\
+            audience=some_awesome_audience",
+        );
+        assert!(request.headers().get("authorization").is_none());
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
 }
@@ -3,6 +3,7 @@ use reqwest::RequestBuilder;
 pub use serde::{Deserialize, Serialize};
 
 pub mod authorization_code_flow;
+pub mod authorize_url;
 pub mod implicit_flow;
 pub mod pkce;
 
@@ -16,6 +17,8 @@ pub trait AuthorizeApplication {
         -> RequestBuilder;
 
     fn implicit_flow(&self, request: implicit_flow::RequestParameters) -> RequestBuilder;
+
+    fn authorize_url(&self, client_id: String) -> authorize_url::AuthorizeUrlBuilder;
 }
 
 impl AuthorizeApplication for Api {
@@ -45,12 +48,23 @@ impl AuthorizeApplication for Api {
 
         self.client.get(url).query(&request)
     }
+
+    fn authorize_url(&self, client_id: String) -> authorize_url::AuthorizeUrlBuilder {
+        let endpoint = String::from("/authorize");
+        let url = self.base_url.join(&endpoint).unwrap();
+
+        authorize_url::AuthorizeUrlBuilder::new(url, client_id)
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
+    use crate::authentication::test_keys::{some_awesome_jwks, RSA_PRIVATE_KEY};
     use crate::authentication::*;
+    use crate::error::{AuthenticationError, CallbackError, IdTokenError};
+    use crate::models::ResponseType;
 
     #[test]
     fn authorization_code_flow_build_request() {
@@ -60,7 +74,7 @@ mod tests {
         let parameters = authorize_application::authorization_code_flow::RequestParameters {
             audience: Some(String::from("some_awesome_audience")),
             scope: Some(String::from("some_awesome_scope")),
-            response_type: String::from("some_awesome_response_type"),
+            response_type: ResponseType::Code,
             client_id: String::from("some_awesome_client_id"),
             state: Some(String::from("some_awesome_state")),
             redirect_uri: None,
@@ -75,7 +89,7 @@ mod tests {
             "https://your_domain/authorize?\
             audience=some_awesome_audience&\
             scope=some_awesome_scope&\
-            response_type=some_awesome_response_type&\
+            response_type=code&\
             client_id=some_awesome_client_id&\
             state=some_awesome_state",
         );
@@ -93,7 +107,7 @@ mod tests {
         let parameters = authorize_application::pkce::RequestParameters {
             audience: Some(String::from("some_awesome_audience")),
             scope: Some(String::from("some_awesome_scope")),
-            response_type: String::from("some_awesome_response_type"),
+            response_type: ResponseType::Code,
             client_id: String::from("some_awesome_client_id"),
             state: Some(String::from("some_awesome_state")),
             redirect_uri: None,
@@ -110,7 +124,7 @@ mod tests {
             "https://your_domain/authorize?\
             audience=some_awesome_audience&\
             scope=some_awesome_scope&\
-            response_type=some_awesome_response_type&\
+            response_type=code&\
             client_id=some_awesome_client_id&\
             state=some_awesome_state&\
             code_challenge_method=some_awesome_code_challenege_method&\
@@ -130,7 +144,7 @@ mod tests {
         let parameters = authorize_application::implicit_flow::RequestParameters {
             audience: Some(String::from("some_awesome_audience")),
             scope: Some(String::from("some_awesome_scope")),
-            response_type: String::from("some_awesome_response_type"),
+            response_type: ResponseType::Token,
             client_id: String::from("some_awesome_client_id"),
             state: Some(String::from("some_awesome_state")),
             redirect_uri: None,
@@ -146,7 +160,7 @@ mod tests {
             "https://your_domain/authorize?\
             audience=some_awesome_audience&\
             scope=some_awesome_scope&\
-            response_type=some_awesome_response_type&\
+            response_type=token&\
             client_id=some_awesome_client_id&\
             state=some_awesome_state",
         );
@@ -155,4 +169,271 @@ mod tests {
         assert_eq!(request.headers().is_empty(), true);
         assert_eq!(request.body().is_none(), true);
     }
+
+    #[test]
+    fn authorize_url_build() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+        let authorize_application = Api::init(base_url, authentication);
+        let authorize_url = authorize_application
+            .authorize_url(String::from("some_awesome_client_id"))
+            .redirect_uri(String::from("https://some_awesome_redirect_uri/callback"))
+            .scope(String::from("openid profile"))
+            .state(String::from("some_awesome_state"))
+            .build();
+        let nonce = authorize_url.nonce.clone().unwrap();
+        let test_url = format!(
+            "https://your_domain/authorize?\
+            response_type=code&\
+            client_id=some_awesome_client_id&\
+            redirect_uri=https%3A%2F%2Fsome_awesome_redirect_uri%2Fcallback&\
+            scope=openid+profile&\
+            state=some_awesome_state&\
+            nonce={}",
+            nonce
+        );
+        assert_eq!(authorize_url.url.as_str(), test_url);
+        assert_eq!(nonce.len(), 32);
+
+        let stored = serde_json::to_string(&authorize_url).unwrap();
+        let authorize_url = serde_json::from_str::<authorize_url::AuthorizeUrl>(&stored).unwrap();
+        assert_eq!(authorize_url.url.as_str(), test_url);
+        assert_eq!(authorize_url.nonce, Some(nonce));
+
+        let callback = Url::parse(
+            "https://some_awesome_redirect_uri/callback?code=some_awesome_code&state=some_awesome_state",
+        )
+        .unwrap();
+        let parameters = authorize_url
+            .validate_callback(&callback)
+            .unwrap()
+            .token_request(Some(String::from("some_awesome_client_secret")));
+        assert_eq!(parameters.code, "some_awesome_code");
+        assert_eq!(
+            parameters.redirect_uri.unwrap(),
+            "https://some_awesome_redirect_uri/callback"
+        );
+    }
+
+    #[test]
+    fn authorize_url_build_with_pkce() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+        let authorize_application = Api::init(base_url, authentication);
+        let authorize_url = authorize_application
+            .authorize_url(String::from("some_awesome_client_id"))
+            .state(String::from("some_awesome_state"))
+            .code_verifier(String::from("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"))
+            .build();
+        let test_url = String::from(
+            "https://your_domain/authorize?\
+            response_type=code&\
+            client_id=some_awesome_client_id&\
+            state=some_awesome_state&\
+            code_challenge=E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM&\
+            code_challenge_method=S256",
+        );
+        assert_eq!(authorize_url.url.as_str(), test_url);
+
+        let stored = serde_json::to_string(&authorize_url).unwrap();
+        let authorize_url = serde_json::from_str::<authorize_url::AuthorizeUrl>(&stored).unwrap();
+        let callback = Url::parse(
+            "https://some_awesome_redirect_uri/callback?code=some_awesome_code&state=some_awesome_state",
+        )
+        .unwrap();
+        let parameters = authorize_url
+            .validate_callback(&callback)
+            .unwrap()
+            .pkce_token_request()
+            .unwrap();
+        assert_eq!(parameters.code, "some_awesome_code");
+        assert_eq!(
+            parameters.code_verifier,
+            "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"
+        );
+
+        let authorize_url = authorize_application
+            .authorize_url(String::from("some_awesome_client_id"))
+            .pkce()
+            .build();
+        assert_eq!(authorize_url.code_verifier.unwrap().len(), 64);
+    }
+
+    #[test]
+    fn authorize_url_validate_callback_error() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+        let authorize_application = Api::init(base_url, authentication);
+        let authorize_url = authorize_application
+            .authorize_url(String::from("some_awesome_client_id"))
+            .build();
+        assert!(authorize_url.nonce.is_none());
+
+        let callback = Url::parse("https://some_awesome_redirect_uri/callback?code=some_awesome_code&state=some_forged_state").unwrap();
+        assert!(matches!(
+            authorize_url.validate_callback(&callback),
+            Err(CallbackError::StateMismatch)
+        ));
+
+        let callback = Url::parse(&format!(
+            "https://some_awesome_redirect_uri/callback?error=access_denied&error_description=some_awesome_description&state={}",
+            authorize_url.state
+        ))
+        .unwrap();
+        assert!(matches!(
+            authorize_url.validate_callback(&callback),
+            Err(CallbackError::AuthenticationError(
+                AuthenticationError::AccessDenied(_)
+            ))
+        ));
+    }
+
+    fn some_awesome_id_token(mut claims: serde_json::Value) -> String {
+        use jsonwebtoken::{Algorithm, EncodingKey, Header};
+        use std::time::{SystemTime, UNIX_EPOCH};
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        claims["exp"] = serde_json::json!(now + 120);
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some(String::from("some_awesome_key_id"));
+        let key = EncodingKey::from_rsa_pem(RSA_PRIVATE_KEY.as_bytes()).unwrap();
+        jsonwebtoken::encode(&header, &claims, &key).unwrap()
+    }
+
+    #[test]
+    fn authorize_url_validate_fragment() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+        let authorize_application = Api::init(base_url, authentication);
+        let authorize_url = authorize_application
+            .authorize_url(String::from("some_awesome_client_id"))
+            .response_type(ResponseType::IdTokenToken)
+            .state(String::from("some_awesome_state"))
+            .nonce(String::from("some_awesome_nonce"))
+            .build();
+        let at_hash = id_token::left_half_hash("RS256", "some_awesome_access_token").unwrap();
+        let id_token = some_awesome_id_token(serde_json::json!({
+            "iss": "https://your_domain/",
+            "aud": "some_awesome_client_id",
+            "nonce": "some_awesome_nonce",
+            "at_hash": at_hash,
+        }));
+        let callback = Url::parse(&format!(
+            "https://some_awesome_redirect_uri/callback#access_token=some_awesome_access_token&\
+            token_type=Bearer&expires_in=7200&id_token={}&state=some_awesome_state",
+            id_token
+        ))
+        .unwrap();
+        let response = authorize_url
+            .validate_fragment(&callback, &some_awesome_jwks())
+            .unwrap();
+        assert_eq!(response.access_token.unwrap(), "some_awesome_access_token");
+        assert_eq!(response.expires_in, Some(7200));
+
+        let callback = Url::parse(&format!(
+            "https://some_awesome_redirect_uri/callback#access_token=some_awesome_access_token&\
+            token_type=Bearer&expires_in=some_awesome_expiry&id_token={}&state=some_awesome_state",
+            id_token
+        ))
+        .unwrap();
+        assert!(matches!(
+            authorize_url.validate_fragment(&callback, &some_awesome_jwks()),
+            Err(CallbackError::InvalidParameters(_))
+        ));
+
+        let callback = Url::parse(&format!(
+            "https://some_awesome_redirect_uri/callback#access_token=some_forged_access_token&\
+            id_token={}&state=some_awesome_state",
+            id_token
+        ))
+        .unwrap();
+        assert!(matches!(
+            authorize_url.validate_fragment(&callback, &some_awesome_jwks()),
+            Err(CallbackError::IdTokenError(IdTokenError::HashMismatch(_)))
+        ));
+
+        let id_token = some_awesome_id_token(serde_json::json!({
+            "iss": "https://your_domain/",
+            "aud": "some_awesome_client_id",
+            "nonce": "some_forged_nonce",
+            "at_hash": at_hash,
+        }));
+        let callback = Url::parse(&format!(
+            "https://some_awesome_redirect_uri/callback#access_token=some_awesome_access_token&\
+            id_token={}&state=some_awesome_state",
+            id_token
+        ))
+        .unwrap();
+        assert!(matches!(
+            authorize_url.validate_fragment(&callback, &some_awesome_jwks()),
+            Err(CallbackError::IdTokenError(IdTokenError::NonceMismatch))
+        ));
+
+        let id_token = some_awesome_id_token(serde_json::json!({
+            "iss": "https://your_domain/",
+            "aud": "some_other_client_id",
+            "nonce": "some_awesome_nonce",
+            "at_hash": at_hash,
+        }));
+        let callback = Url::parse(&format!(
+            "https://some_awesome_redirect_uri/callback#access_token=some_awesome_access_token&\
+            id_token={}&state=some_awesome_state",
+            id_token
+        ))
+        .unwrap();
+        assert!(matches!(
+            authorize_url.validate_fragment(&callback, &some_awesome_jwks()),
+            Err(CallbackError::IdTokenError(IdTokenError::Invalid(_)))
+        ));
+
+        let id_token = some_awesome_id_token(serde_json::json!({
+            "iss": "https://your_domain/",
+            "aud": "some_awesome_client_id",
+            "nonce": "some_awesome_nonce",
+            "at_hash": at_hash,
+        }));
+        let (unsigned_token, _) = id_token.rsplit_once('.').unwrap();
+        let callback = Url::parse(&format!(
+            "https://some_awesome_redirect_uri/callback#access_token=some_awesome_access_token&\
+            id_token={}.some_forged_signature&state=some_awesome_state",
+            unsigned_token
+        ))
+        .unwrap();
+        assert!(matches!(
+            authorize_url.validate_fragment(&callback, &some_awesome_jwks()),
+            Err(CallbackError::IdTokenError(IdTokenError::Invalid(_)))
+        ));
+    }
+
+    #[test]
+    fn authorize_url_validate_fragment_requires_at_hash_in_hybrid_flow() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+        let authorize_application = Api::init(base_url, authentication);
+        let authorize_url = authorize_application
+            .authorize_url(String::from("some_awesome_client_id"))
+            .response_type(ResponseType::CodeIdTokenToken)
+            .state(String::from("some_awesome_state"))
+            .nonce(String::from("some_awesome_nonce"))
+            .build();
+        let c_hash = id_token::left_half_hash("RS256", "some_awesome_code").unwrap();
+        let id_token = some_awesome_id_token(serde_json::json!({
+            "iss": "https://your_domain/",
+            "aud": "some_awesome_client_id",
+            "nonce": "some_awesome_nonce",
+            "c_hash": c_hash,
+        }));
+        let callback = Url::parse(&format!(
+            "https://some_awesome_redirect_uri/callback#code=some_awesome_code&\
+            access_token=some_awesome_access_token&id_token={}&state=some_awesome_state",
+            id_token
+        ))
+        .unwrap();
+        assert!(matches!(
+            authorize_url.validate_fragment(&callback, &some_awesome_jwks()),
+            Err(CallbackError::IdTokenError(IdTokenError::MissingClaim(_)))
+        ));
+    }
 }
@@ -1,4 +1,5 @@
 use crate::authentication::authorize_application::*;
+use crate::models::ResponseType;
 
 #[derive(Serialize, Deserialize)]
 pub struct RequestParameters {
@@ -6,7 +7,7 @@ pub struct RequestParameters {
     pub audience: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope: Option<String>,
-    pub response_type: String,
+    pub response_type: ResponseType,
     pub client_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
@@ -0,0 +1,352 @@
+use crate::authentication::authorize_application::implicit_flow;
+use crate::authentication::get_token::{
+    authorization_code_flow, authorization_code_flow_with_pkce,
+};
+use crate::authentication::id_token;
+use crate::error::{AuthenticationError, CallbackError};
+use crate::models::ResponseType;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::jwk::JwkSet;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Builds the `/authorize` URL a user agent is redirected to.
+pub struct AuthorizeUrlBuilder {
+    url: Url,
+    client_id: String,
+    response_type: ResponseType,
+    redirect_uri: Option<String>,
+    scope: Option<String>,
+    audience: Option<String>,
+    connection: Option<String>,
+    organization: Option<String>,
+    prompt: Option<String>,
+    state: Option<String>,
+    nonce: Option<String>,
+    code_verifier: Option<String>,
+    parameters: Vec<(String, String)>,
+}
+
+impl AuthorizeUrlBuilder {
+    pub fn new(url: Url, client_id: String) -> Self {
+        AuthorizeUrlBuilder {
+            url,
+            client_id,
+            response_type: ResponseType::Code,
+            redirect_uri: None,
+            scope: None,
+            audience: None,
+            connection: None,
+            organization: None,
+            prompt: None,
+            state: None,
+            nonce: None,
+            code_verifier: None,
+            parameters: Vec::new(),
+        }
+    }
+
+    pub fn response_type(mut self, response_type: ResponseType) -> Self {
+        self.response_type = response_type;
+        self
+    }
+
+    pub fn redirect_uri(mut self, redirect_uri: String) -> Self {
+        self.redirect_uri = Some(redirect_uri);
+        self
+    }
+
+    pub fn scope(mut self, scope: String) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    pub fn audience(mut self, audience: String) -> Self {
+        self.audience = Some(audience);
+        self
+    }
+
+    pub fn connection(mut self, connection: String) -> Self {
+        self.connection = Some(connection);
+        self
+    }
+
+    pub fn organization(mut self, organization: String) -> Self {
+        self.organization = Some(organization);
+        self
+    }
+
+    pub fn prompt(mut self, prompt: String) -> Self {
+        self.prompt = Some(prompt);
+        self
+    }
+
+    /// Uses the given `state` instead of generating one.
+    pub fn state(mut self, state: String) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Uses the given `nonce` instead of generating one.
+    pub fn nonce(mut self, nonce: String) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Protects the authorization code with PKCE: a random `code_verifier` is generated and its
+    /// `S256` `code_challenge` is sent. Public clients (native apps, SPAs) must use it.
+    pub fn pkce(self) -> Self {
+        let code_verifier = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(64)
+            .map(char::from)
+            .collect();
+        self.code_verifier(code_verifier)
+    }
+
+    /// Like [`pkce`](AuthorizeUrlBuilder::pkce), with the given `code_verifier`.
+    pub fn code_verifier(mut self, code_verifier: String) -> Self {
+        self.code_verifier = Some(code_verifier);
+        self
+    }
+
+    /// Adds any other parameter supported by `/authorize`.
+    pub fn parameter(mut self, key: String, value: String) -> Self {
+        self.parameters.push((key, value));
+        self
+    }
+
+    /// Builds the URL. A `state` is always included; a `nonce` is included when an ID token is
+    /// requested, either through the `response_type` or the `openid` scope.
+    pub fn build(self) -> AuthorizeUrl {
+        let state = self.state.unwrap_or_else(random_string);
+        let nonce = match self.nonce {
+            Some(nonce) => Some(nonce),
+            None if requests_id_token(self.response_type, self.scope.as_deref()) => {
+                Some(random_string())
+            }
+            None => None,
+        };
+
+        let mut url = self.url;
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("response_type", self.response_type.as_str());
+            query.append_pair("client_id", &self.client_id);
+            let optional = [
+                ("redirect_uri", &self.redirect_uri),
+                ("scope", &self.scope),
+                ("audience", &self.audience),
+                ("connection", &self.connection),
+                ("organization", &self.organization),
+                ("prompt", &self.prompt),
+            ];
+            for (key, value) in optional.iter() {
+                if let Some(value) = value {
+                    query.append_pair(key, value);
+                }
+            }
+            query.append_pair("state", &state);
+            if let Some(nonce) = &nonce {
+                query.append_pair("nonce", nonce);
+            }
+            if let Some(code_verifier) = &self.code_verifier {
+                let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier));
+                query.append_pair("code_challenge", &code_challenge);
+                query.append_pair("code_challenge_method", "S256");
+            }
+            for (key, value) in &self.parameters {
+                query.append_pair(key, value);
+            }
+        }
+
+        AuthorizeUrl {
+            url,
+            state,
+            nonce,
+            code_verifier: self.code_verifier,
+            client_id: self.client_id,
+            redirect_uri: self.redirect_uri,
+            response_type: self.response_type,
+        }
+    }
+}
+
+/// An `/authorize` URL along with the values needed to validate the callback. Keep it (e.g. in
+/// the user's session) until the user agent returns to the `redirect_uri`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizeUrl {
+    #[serde(with = "url_string")]
+    pub url: Url,
+    pub state: String,
+    pub nonce: Option<String>,
+    /// The PKCE `code_verifier`, sent with the token request.
+    #[serde(default)]
+    pub code_verifier: Option<String>,
+    pub client_id: String,
+    pub redirect_uri: Option<String>,
+    pub response_type: ResponseType,
+}
+
+impl AuthorizeUrl {
+    /// Validates the query of the URL the user agent was redirected to and extracts the
+    /// authorization code.
+    pub fn validate_callback(&self, callback: &Url) -> Result<AuthorizationCode, CallbackError> {
+        let parameters = self.validate_parameters(callback.query_pairs().into_owned())?;
+        match parameters.get("code").and_then(|code| code.as_str()) {
+            Some(code) => Ok(AuthorizationCode {
+                code: code.to_string(),
+                code_verifier: self.code_verifier.clone(),
+                client_id: self.client_id.clone(),
+                redirect_uri: self.redirect_uri.clone(),
+            }),
+            None => Err(CallbackError::MissingParameter(String::from("code"))),
+        }
+    }
+
+    /// Validates the fragment of the URL the user agent was redirected to by the implicit or
+    /// hybrid flow. The parameters required by the `response_type` must be present. A returned
+    /// ID token is verified against `jwks`, the tenant's signing keys, and must be issued by the
+    /// tenant for this client, carry the `nonce`, and match the returned `access_token`
+    /// (`at_hash`) and `code` (`c_hash`).
+    pub fn validate_fragment(
+        &self,
+        callback: &Url,
+        jwks: &JwkSet,
+    ) -> Result<implicit_flow::Response, CallbackError> {
+        let fragment = callback.fragment().unwrap_or_default();
+        let pairs = serde_urlencoded::from_str::<Vec<(String, String)>>(fragment)
+            .map_err(|error| CallbackError::InvalidParameters(error.to_string()))?;
+        let parameters = self.validate_parameters(pairs.into_iter())?;
+        let response_type = self.response_type.as_str();
+        for expected in response_type.split(' ') {
+            let parameter = match expected {
+                "token" => "access_token",
+                parameter => parameter,
+            };
+            if !parameters.contains_key(parameter) {
+                return Err(CallbackError::MissingParameter(parameter.to_string()));
+            }
+        }
+        let response = serde_json::from_value::<implicit_flow::Response>(
+            serde_json::Value::Object(parameters),
+        )
+        .map_err(|error| CallbackError::InvalidParameters(error.to_string()))?;
+
+        if let Some(id_token) = &response.id_token {
+            let issuer = self.url.join("/").unwrap();
+            let (header, claims) =
+                id_token::verify(id_token, jwks, issuer.as_str(), &self.client_id)?;
+            if let Some(nonce) = &self.nonce {
+                id_token::validate_nonce(&claims, nonce)?;
+            }
+            if let Some(access_token) = &response.access_token {
+                id_token::validate_hash(
+                    &header.alg,
+                    claims.at_hash.as_ref(),
+                    access_token,
+                    matches!(
+                        self.response_type,
+                        ResponseType::IdTokenToken | ResponseType::CodeIdTokenToken
+                    ),
+                    "at_hash",
+                )?;
+            }
+            if let Some(code) = &response.code {
+                id_token::validate_hash(&header.alg, claims.c_hash.as_ref(), code, true, "c_hash")?;
+            }
+        }
+        Ok(response)
+    }
+
+    /// Checks `state` and turns an `error` into a [`CallbackError`].
+    fn validate_parameters(
+        &self,
+        pairs: impl Iterator<Item = (String, String)>,
+    ) -> Result<serde_json::Map<String, serde_json::Value>, CallbackError> {
+        let parameters = pairs
+            .map(|(key, value)| (key, serde_json::Value::String(value)))
+            .collect::<serde_json::Map<String, serde_json::Value>>();
+        let state = parameters.get("state").and_then(|state| state.as_str());
+        if state != Some(self.state.as_str()) {
+            return Err(CallbackError::StateMismatch);
+        }
+        if parameters.contains_key("error") {
+            let error = serde_json::from_value::<AuthenticationError>(serde_json::Value::Object(
+                parameters,
+            ))
+            .map_err(|error| CallbackError::InvalidParameters(error.to_string()))?;
+            return Err(CallbackError::AuthenticationError(error));
+        }
+        Ok(parameters)
+    }
+}
+
+/// `Url` as its string form.
+mod url_string {
+    use reqwest::Url;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(url: &Url, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(url.as_str())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Url, D::Error> {
+        Url::parse(&String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Authorization code returned to the `redirect_uri`, ready to be exchanged for tokens.
+#[derive(Debug, Clone)]
+pub struct AuthorizationCode {
+    pub code: String,
+    pub code_verifier: Option<String>,
+    pub client_id: String,
+    pub redirect_uri: Option<String>,
+}
+
+impl AuthorizationCode {
+    /// `client_secret` may be omitted when the client authenticates with a private key JWT.
+    pub fn token_request(
+        self,
+        client_secret: Option<String>,
+    ) -> authorization_code_flow::RequestParameters {
+        authorization_code_flow::RequestParameters {
+            client_id: Some(self.client_id),
+            client_secret,
+            code: self.code,
+            redirect_uri: self.redirect_uri,
+        }
+    }
+
+    /// Token request of a public client that started the flow with PKCE; `None` without a
+    /// `code_verifier`.
+    pub fn pkce_token_request(
+        self,
+    ) -> Option<authorization_code_flow_with_pkce::RequestParameters> {
+        Some(authorization_code_flow_with_pkce::RequestParameters {
+            client_id: Some(self.client_id),
+            code: self.code,
+            code_verifier: self.code_verifier?,
+            redirect_uri: self.redirect_uri,
+        })
+    }
+}
+
+/// Whether an ID token is requested, either through the `response_type` or the `openid` scope.
+pub(crate) fn requests_id_token(response_type: ResponseType, scope: Option<&str>) -> bool {
+    response_type.as_str().contains("id_token")
+        || scope.is_some_and(|scope| scope.split(' ').any(|scope| scope == "openid"))
+}
+
+pub(crate) fn random_string() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect()
+}
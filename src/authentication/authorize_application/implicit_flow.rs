@@ -1,4 +1,5 @@
 use crate::authentication::authorize_application::*;
+use crate::models::ResponseType;
 
 #[derive(Serialize, Deserialize)]
 pub struct RequestParameters {
@@ -6,7 +7,7 @@ pub struct RequestParameters {
     pub audience: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope: Option<String>,
-    pub response_type: String,
+    pub response_type: ResponseType,
     pub client_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
@@ -19,3 +20,36 @@ pub struct RequestParameters {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prompt: Option<String>,
 }
+
+/// Parameters returned in the redirect URI fragment by the implicit and hybrid flows.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Response {
+    pub access_token: Option<String>,
+    pub token_type: Option<String>,
+    #[serde(default, with = "optional_u64")]
+    pub expires_in: Option<u64>,
+    pub id_token: Option<String>,
+    pub code: Option<String>,
+    pub scope: Option<String>,
+    pub state: String,
+}
+
+/// Fragment parameters are strings, including `expires_in`.
+mod optional_u64 {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_str(&value.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| value.parse().map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
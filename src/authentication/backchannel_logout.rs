@@ -0,0 +1,369 @@
+//! OIDC Back-Channel Logout: Auth0 POSTs a signed `logout_token` to the application's
+//! back-channel logout URL when a session ends, and the application ends its own session for the
+//! `sid` or `sub` it names.
+//!
+//! ```ignore
+//! let validator = LogoutTokenValidator::fetch(&api, client_id).await?;
+//! // In the handler of `POST /backchannel-logout`:
+//! match validator.validate_request_body(&body).await {
+//!     Ok(logout_token) => { /* end sessions for logout_token.sid / .sub, respond 200 */ }
+//!     Err(_) => { /* respond 400 */ }
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use reqwest::{Client, RequestBuilder, Url};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::authentication::id_token;
+use crate::authentication::Api;
+use crate::error::{Error, LogoutTokenError};
+
+pub const BACKCHANNEL_LOGOUT_EVENT: &str = "http://schemas.openid.net/event/backchannel-logout";
+
+/// `typ` header of a logout token, which tells it apart from other JWTs signed with the same key.
+pub const LOGOUT_TOKEN_TYPE: &str = "logout+jwt";
+
+pub trait BackchannelLogout {
+    /// The tenant's signing keys.
+    fn jwks(&self) -> RequestBuilder;
+}
+
+impl BackchannelLogout for Api {
+    fn jwks(&self) -> RequestBuilder {
+        let endpoint = String::from("/.well-known/jwks.json");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.client.get(url)
+    }
+}
+
+/// Claims of a validated `logout_token`. `jti` can be used to reject replayed tokens.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogoutToken {
+    pub iss: String,
+    /// A single audience or an array of audiences.
+    pub aud: serde_json::Value,
+    pub iat: u64,
+    pub exp: u64,
+    pub jti: Option<String>,
+    pub sub: Option<String>,
+    pub sid: Option<String>,
+    pub events: HashMap<String, serde_json::Value>,
+    pub nonce: Option<String>,
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+/// Minimum time between two refreshes of the signing keys triggered by an unknown `kid`.
+const JWKS_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+pub struct LogoutTokenValidator {
+    issuer: String,
+    client_id: String,
+    keys: Mutex<SigningKeys>,
+    /// Where the keys were fetched from, to refresh them when the tenant rotates its keys.
+    jwks_source: Option<(Client, Url)>,
+}
+
+struct SigningKeys {
+    jwks: JwkSet,
+    refreshed_at: Option<Instant>,
+}
+
+impl LogoutTokenValidator {
+    /// `issuer` is the tenant's base URL, e.g. `https://YOUR_DOMAIN/`. The keys are never
+    /// refreshed; use [`LogoutTokenValidator::fetch`] to follow key rotation.
+    pub fn new(issuer: String, client_id: String, jwks: JwkSet) -> Self {
+        LogoutTokenValidator {
+            issuer,
+            client_id,
+            keys: Mutex::new(SigningKeys {
+                jwks,
+                refreshed_at: None,
+            }),
+            jwks_source: None,
+        }
+    }
+
+    /// Creates a validator for tokens issued by the tenant of `api`, fetching its signing keys.
+    /// A token signed with an unknown key makes the validator fetch the keys again, at most once
+    /// a minute.
+    pub async fn fetch(api: &Api, client_id: String) -> Result<Self, Error> {
+        let jwks = api.send(api.jwks()).await?.json::<JwkSet>().await?;
+        let jwks_uri = api.base_url.join("/.well-known/jwks.json").unwrap();
+        let mut validator = LogoutTokenValidator::new(api.base_url.to_string(), client_id, jwks);
+        validator.jwks_source = Some((api.client.clone(), jwks_uri));
+        Ok(validator)
+    }
+
+    /// Validates the `application/x-www-form-urlencoded` body of a back-channel logout request.
+    pub async fn validate_request_body(
+        &self,
+        body: &[u8],
+    ) -> Result<LogoutToken, LogoutTokenError> {
+        let parameters = serde_urlencoded::from_bytes::<HashMap<String, String>>(body)
+            .map_err(|_| LogoutTokenError::MissingToken)?;
+        match parameters.get("logout_token") {
+            Some(logout_token) => self.validate(logout_token).await,
+            None => Err(LogoutTokenError::MissingToken),
+        }
+    }
+
+    /// Verifies the signature, issuer, audience and expiry of a `logout_token`, that its `typ`
+    /// is absent or `logout+jwt`, and that it carries the back-channel logout event, a `sid` or
+    /// `sub`, and no `nonce`.
+    pub async fn validate(&self, logout_token: &str) -> Result<LogoutToken, LogoutTokenError> {
+        let header = jsonwebtoken::decode_header(logout_token)?;
+        if let Some(typ) = header.typ.as_deref() {
+            let typ_is_logout_token = typ.eq_ignore_ascii_case(LOGOUT_TOKEN_TYPE)
+                || typ.eq_ignore_ascii_case("application/logout+jwt");
+            if !typ_is_logout_token {
+                return Err(LogoutTokenError::InvalidType(typ.to_string()));
+            }
+        }
+        if let Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 = header.alg {
+            return Err(jsonwebtoken::errors::Error::from(
+                jsonwebtoken::errors::ErrorKind::InvalidAlgorithm,
+            )
+            .into());
+        }
+        let key = self.decoding_key(header.kid.as_deref()).await?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&self.issuer]);
+        validation.set_audience(&[&self.client_id]);
+        validation.set_required_spec_claims(&["iss", "aud", "exp"]);
+        let claims = jsonwebtoken::decode::<LogoutToken>(logout_token, &key, &validation)?.claims;
+
+        if !claims
+            .events
+            .get(BACKCHANNEL_LOGOUT_EVENT)
+            .is_some_and(|event| event.is_object())
+        {
+            return Err(LogoutTokenError::MissingEvent);
+        }
+        if claims.sid.is_none() && claims.sub.is_none() {
+            return Err(LogoutTokenError::MissingSubject);
+        }
+        if claims.nonce.is_some() {
+            return Err(LogoutTokenError::NonceNotAllowed);
+        }
+        Ok(claims)
+    }
+
+    async fn decoding_key(&self, kid: Option<&str>) -> Result<DecodingKey, LogoutTokenError> {
+        {
+            let mut keys = self.keys.lock().await;
+            if let Some(jwk) = id_token::signing_key(&keys.jwks, kid) {
+                return Ok(DecodingKey::from_jwk(jwk)?);
+            }
+            let refreshed_recently = keys
+                .refreshed_at
+                .is_some_and(|refreshed_at| refreshed_at.elapsed() < JWKS_REFRESH_INTERVAL);
+            if self.jwks_source.is_none() || refreshed_recently {
+                return Err(LogoutTokenError::UnknownKey(kid.map(String::from)));
+            }
+            // Claimed before fetching, so concurrent requests don't refresh the keys again
+            keys.refreshed_at = Some(Instant::now());
+        }
+
+        // The keys aren't locked while fetching, so other requests aren't held up by it
+        let jwks = self.fetch_jwks().await;
+        let mut keys = self.keys.lock().await;
+        if let Some(jwks) = jwks {
+            keys.jwks = jwks;
+        }
+        let jwk = id_token::signing_key(&keys.jwks, kid)
+            .ok_or_else(|| LogoutTokenError::UnknownKey(kid.map(String::from)))?;
+        Ok(DecodingKey::from_jwk(jwk)?)
+    }
+
+    async fn fetch_jwks(&self) -> Option<JwkSet> {
+        let (client, jwks_uri) = self.jwks_source.as_ref()?;
+        let response = match client.get(jwks_uri.clone()).send().await {
+            Ok(response) => response.error_for_status(),
+            Err(error) => Err(error),
+        };
+        let jwks = match response {
+            Ok(response) => response.json::<JwkSet>().await,
+            Err(error) => Err(error),
+        };
+        match jwks {
+            Ok(jwks) => Some(jwks),
+            Err(error) => {
+                tracing::warn!("Refreshing {} failed: {}", jwks_uri, error);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authentication::test_keys::{some_awesome_jwks, RSA_PRIVATE_KEY};
+    use jsonwebtoken::{EncodingKey, Header};
+    use serde_json::json;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn some_awesome_validator() -> LogoutTokenValidator {
+        LogoutTokenValidator::new(
+            String::from("https://YOUR_DOMAIN/"),
+            String::from("some_awesome_client_id"),
+            some_awesome_jwks(),
+        )
+    }
+
+    fn some_awesome_logout_token(claims: serde_json::Value) -> String {
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some(String::from("some_awesome_key_id"));
+        header.typ = Some(String::from(LOGOUT_TOKEN_TYPE));
+        let key = EncodingKey::from_rsa_pem(RSA_PRIVATE_KEY.as_bytes()).unwrap();
+        jsonwebtoken::encode(&header, &claims, &key).unwrap()
+    }
+
+    fn some_awesome_claims() -> serde_json::Value {
+        let iat = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        json!({
+            "iss": "https://YOUR_DOMAIN/",
+            "aud": "some_awesome_client_id",
+            "iat": iat,
+            "exp": iat + 120,
+            "jti": "some_awesome_jti",
+            "sid": "some_awesome_sid",
+            "events": { BACKCHANNEL_LOGOUT_EVENT: {} }
+        })
+    }
+
+    #[tokio::test]
+    async fn validate_logout_token() {
+        let logout_token = some_awesome_logout_token(some_awesome_claims());
+        let body = format!("logout_token={}", logout_token);
+        let claims = some_awesome_validator()
+            .validate_request_body(body.as_bytes())
+            .await
+            .unwrap();
+        assert_eq!(claims.sid.as_deref(), Some("some_awesome_sid"));
+        assert_eq!(claims.jti.as_deref(), Some("some_awesome_jti"));
+    }
+
+    #[tokio::test]
+    async fn validate_logout_token_rejects_nonce() {
+        let mut claims = some_awesome_claims();
+        claims["nonce"] = json!("some_awesome_nonce");
+        let logout_token = some_awesome_logout_token(claims);
+        assert!(matches!(
+            some_awesome_validator().validate(&logout_token).await,
+            Err(LogoutTokenError::NonceNotAllowed)
+        ));
+    }
+
+    #[tokio::test]
+    async fn validate_logout_token_requires_event() {
+        let mut claims = some_awesome_claims();
+        claims["events"] = json!({});
+        let logout_token = some_awesome_logout_token(claims);
+        assert!(matches!(
+            some_awesome_validator().validate(&logout_token).await,
+            Err(LogoutTokenError::MissingEvent)
+        ));
+    }
+
+    #[tokio::test]
+    async fn validate_logout_token_requires_sid_or_sub() {
+        let mut claims = some_awesome_claims();
+        claims.as_object_mut().unwrap().remove("sid");
+        let logout_token = some_awesome_logout_token(claims);
+        assert!(matches!(
+            some_awesome_validator().validate(&logout_token).await,
+            Err(LogoutTokenError::MissingSubject)
+        ));
+    }
+
+    #[tokio::test]
+    async fn validate_logout_token_rejects_other_audience() {
+        let mut claims = some_awesome_claims();
+        claims["aud"] = json!("some_other_client_id");
+        let logout_token = some_awesome_logout_token(claims);
+        assert!(matches!(
+            some_awesome_validator().validate(&logout_token).await,
+            Err(LogoutTokenError::Invalid(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn validate_logout_token_rejects_other_type() {
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some(String::from("some_awesome_key_id"));
+        let key = EncodingKey::from_rsa_pem(RSA_PRIVATE_KEY.as_bytes()).unwrap();
+        let id_token = jsonwebtoken::encode(&header, &some_awesome_claims(), &key).unwrap();
+        assert!(matches!(
+            some_awesome_validator().validate(&id_token).await,
+            Err(LogoutTokenError::InvalidType(typ)) if typ == "JWT"
+        ));
+
+        header.typ = None;
+        let logout_token = jsonwebtoken::encode(&header, &some_awesome_claims(), &key).unwrap();
+        assert!(some_awesome_validator()
+            .validate(&logout_token)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn validate_logout_token_refreshes_rotated_keys() {
+        let previous_keys = mockito::mock("GET", "/.well-known/jwks.json")
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"keys":[]}"#)
+            .create();
+        let base_url = Url::parse(&mockito::server_url()).unwrap();
+        let authentication = crate::authentication::AuthenticationMethod::OAuth2Token(
+            String::from("some_awesome_token"),
+        );
+        let api = Api::init(base_url, authentication);
+        let validator = LogoutTokenValidator::fetch(&api, String::from("some_awesome_client_id"))
+            .await
+            .unwrap();
+        previous_keys.assert();
+        drop(previous_keys);
+
+        let rotated_keys = mockito::mock("GET", "/.well-known/jwks.json")
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::to_string(&some_awesome_jwks()).unwrap())
+            .create();
+        let mut claims = some_awesome_claims();
+        claims["iss"] = json!(api.base_url.to_string());
+        let logout_token = some_awesome_logout_token(claims.clone());
+        let logout_token = validator.validate(&logout_token).await.unwrap();
+        assert_eq!(logout_token.sid.as_deref(), Some("some_awesome_sid"));
+
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some(String::from("some_other_key_id"));
+        header.typ = Some(String::from(LOGOUT_TOKEN_TYPE));
+        let key = EncodingKey::from_rsa_pem(RSA_PRIVATE_KEY.as_bytes()).unwrap();
+        let logout_token = jsonwebtoken::encode(&header, &claims, &key).unwrap();
+        assert!(matches!(
+            validator.validate(&logout_token).await,
+            Err(LogoutTokenError::UnknownKey(_))
+        ));
+        rotated_keys.assert();
+    }
+
+    #[tokio::test]
+    async fn validate_request_body_requires_logout_token() {
+        assert!(matches!(
+            some_awesome_validator()
+                .validate_request_body(b"state=some_awesome_state")
+                .await,
+            Err(LogoutTokenError::MissingToken)
+        ));
+    }
+}
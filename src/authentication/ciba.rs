@@ -0,0 +1,142 @@
+//! Client-Initiated Backchannel Authentication: the user approves a login on their own device,
+//! e.g. through a push notification, while the client polls for tokens.
+
+use std::time::Duration;
+
+use crate::authentication::get_token::{GrantTypeParameters, TokenResponse};
+use crate::authentication::Api;
+use crate::error::{AuthenticationError, Error};
+use crate::models::GrantType;
+use reqwest::RequestBuilder;
+pub use serde::{Deserialize, Serialize};
+
+pub mod backchannel_authorize;
+pub mod token;
+
+/// Polling interval used when `/bc-authorize` doesn't return one.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Additional delay requested by a `slow_down` error.
+const SLOW_DOWN_INCREMENT: Duration = Duration::from_secs(5);
+
+pub trait Ciba {
+    fn backchannel_authorize(
+        &self,
+        request: backchannel_authorize::RequestParameters,
+    ) -> RequestBuilder;
+    fn ciba_token(&self, request: token::RequestParameters) -> RequestBuilder;
+}
+
+impl Ciba for Api {
+    fn backchannel_authorize(
+        &self,
+        request: backchannel_authorize::RequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/bc-authorize");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.client_authentication(&request)
+            .form(self.client.post(url))
+    }
+    fn ciba_token(&self, request: token::RequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/oauth/token");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.client_authentication(GrantTypeParameters::new(GrantType::Ciba, &request))
+            .form(self.client.post(url))
+    }
+}
+
+impl Api {
+    /// Polls `/oauth/token` for a backchannel authentication request until the user approves or
+    /// rejects it, or it expires. The client credentials configured on this client are used.
+    pub async fn poll_ciba(
+        &self,
+        authorization: &backchannel_authorize::Response,
+    ) -> Result<TokenResponse, Error> {
+        let mut interval = authorization
+            .interval
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_INTERVAL);
+        loop {
+            let request = self.ciba_token(token::RequestParameters {
+                auth_req_id: authorization.auth_req_id.clone(),
+                client_id: None,
+                client_secret: None,
+            });
+            match self.send(request).await {
+                Err(Error::AuthenticationError(AuthenticationError::AuthorizationPending(_))) => {}
+                Err(Error::AuthenticationError(AuthenticationError::SlowDown(_))) => {
+                    interval += SLOW_DOWN_INCREMENT;
+                }
+                result => return Ok(result?.json().await?),
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authentication::*;
+
+    #[test]
+    fn backchannel_authorize_build_request() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+        let ciba = Api::init(base_url, authentication);
+        let parameters = ciba::backchannel_authorize::RequestParameters {
+            client_id: Some(String::from("some_awesome_client_id")),
+            client_secret: Some(String::from("some_awesome_client_secret")),
+            login_hint: ciba::backchannel_authorize::LoginHint::iss_sub(
+                String::from("https://YOUR_DOMAIN/"),
+                String::from("some_awesome_user_id"),
+            ),
+            scope: String::from("openid"),
+            binding_message: String::from("some_awesome_binding_message"),
+            audience: None,
+            requested_expiry: Some(300),
+        };
+        let request = ciba.backchannel_authorize(parameters).build().unwrap();
+        let test_url = String::from("https://your_domain/bc-authorize");
+        let test_body = String::from(
+            "client_id=some_awesome_client_id&\
+            client_secret=some_awesome_client_secret&\
+            login_hint=%7B%22format%22%3A%22iss_sub%22%2C%22iss%22%3A%22https%3A%2F%2FYOUR_DOMAIN%2F%22%2C%22sub%22%3A%22some_awesome_user_id%22%7D&\
+            scope=openid&\
+            binding_message=some_awesome_binding_message&\
+            requested_expiry=300",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[test]
+    fn ciba_token_build_request() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+        let ciba = Api::init(base_url, authentication);
+        let parameters = ciba::token::RequestParameters {
+            auth_req_id: String::from("some_awesome_auth_req_id"),
+            client_id: Some(String::from("some_awesome_client_id")),
+            client_secret: Some(String::from("some_awesome_client_secret")),
+        };
+        let request = ciba.ciba_token(parameters).build().unwrap();
+        let test_url = String::from("https://your_domain/oauth/token");
+        let test_body = String::from(
+            "grant_type=urn%3Aopenid%3Aparams%3Agrant-type%3Aciba&\
+            auth_req_id=some_awesome_auth_req_id&\
+            client_id=some_awesome_client_id&\
+            client_secret=some_awesome_client_secret",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+}
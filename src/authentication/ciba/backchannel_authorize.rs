@@ -0,0 +1,68 @@
+use crate::authentication::ciba::*;
+use crate::authentication::ClientCredentials;
+
+#[derive(Serialize, Deserialize)]
+pub struct RequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    #[serde(with = "login_hint")]
+    pub login_hint: LoginHint,
+    pub scope: String,
+    pub binding_message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
+    /// Seconds the user has to respond.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_expiry: Option<u64>,
+}
+
+impl ClientCredentials for RequestParameters {
+    fn client_credentials(&self) -> (Option<&str>, Option<&str>) {
+        (self.client_id.as_deref(), self.client_secret.as_deref())
+    }
+}
+
+/// Identifies the user to authenticate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoginHint {
+    pub format: String,
+    pub iss: String,
+    pub sub: String,
+}
+
+impl LoginHint {
+    /// A hint for the user `sub` of the tenant `iss`, e.g. `https://YOUR_DOMAIN/`.
+    pub fn iss_sub(iss: String, sub: String) -> Self {
+        LoginHint {
+            format: String::from("iss_sub"),
+            iss,
+            sub,
+        }
+    }
+}
+
+/// `login_hint` is sent as a JSON string.
+mod login_hint {
+    use super::LoginHint;
+    use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(hint: &LoginHint, serializer: S) -> Result<S::Ok, S::Error> {
+        let json = serde_json::to_string(hint).map_err(S::Error::custom)?;
+        serializer.serialize_str(&json)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<LoginHint, D::Error> {
+        let json = String::deserialize(deserializer)?;
+        serde_json::from_str(&json).map_err(D::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Response {
+    pub auth_req_id: String,
+    pub expires_in: u64,
+    /// Minimum number of seconds between token requests.
+    pub interval: Option<u64>,
+}
@@ -0,0 +1,17 @@
+use crate::authentication::ciba::*;
+use crate::authentication::ClientCredentials;
+
+#[derive(Serialize, Deserialize)]
+pub struct RequestParameters {
+    pub auth_req_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+}
+
+impl ClientCredentials for RequestParameters {
+    fn client_credentials(&self) -> (Option<&str>, Option<&str>) {
+        (self.client_id.as_deref(), self.client_secret.as_deref())
+    }
+}
@@ -1,4 +1,5 @@
-use crate::authentication::Api;
+use crate::authentication::{Api, ClientCredentials};
+use crate::models::GrantType;
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use reqwest::RequestBuilder;
 pub use serde::{Deserialize, Serialize};
@@ -9,6 +10,7 @@ pub mod client_credentials_flow;
 pub mod device_authorization_flow;
 pub mod refresh_token;
 pub mod resource_owner_password;
+pub mod token_exchange;
 pub mod token_exchange_for_native_social;
 
 pub trait GetToken {
@@ -43,6 +45,9 @@ pub trait GetToken {
         &self,
         request: token_exchange_for_native_social::RequestParameters,
     ) -> RequestBuilder;
+
+    /// RFC 8693 token exchange, including Custom Token Exchange profiles.
+    fn token_exchange(&self, request: token_exchange::RequestParameters) -> RequestBuilder;
 }
 
 impl GetToken for Api {
@@ -53,7 +58,11 @@ impl GetToken for Api {
         let endpoint = String::from("/oauth/token");
         let url = self.base_url.join(&endpoint).unwrap();
 
-        self.client.post(url).form(&request)
+        self.client_authentication(GrantTypeParameters::new(
+            GrantType::AuthorizationCode,
+            &request,
+        ))
+        .form(self.client.post(url))
     }
 
     fn authorization_code_flow_with_pkce(
@@ -63,7 +72,11 @@ impl GetToken for Api {
         let endpoint = String::from("/oauth/token");
         let url = self.base_url.join(&endpoint).unwrap();
 
-        self.client.post(url).form(&request)
+        self.client_authentication(GrantTypeParameters::new(
+            GrantType::AuthorizationCode,
+            &request,
+        ))
+        .form(self.client.post(url))
     }
 
     fn client_credentials_flow(
@@ -73,7 +86,11 @@ impl GetToken for Api {
         let endpoint = String::from("/oauth/token");
         let url = self.base_url.join(&endpoint).unwrap();
 
-        self.client.post(url).form(&request)
+        self.client_authentication(GrantTypeParameters::new(
+            GrantType::ClientCredentials,
+            &request,
+        ))
+        .form(self.client.post(url))
     }
 
     fn resource_owner_password(
@@ -82,18 +99,22 @@ impl GetToken for Api {
     ) -> RequestBuilder {
         let endpoint = String::from("/oauth/token");
         let url = self.base_url.join(&endpoint).unwrap();
+        let grant_type = match request.realm {
+            Some(_) => GrantType::PasswordRealm,
+            None => GrantType::Password,
+        };
+        let form = self.client_authentication(GrantTypeParameters::new(grant_type, &request));
 
-        if request.auth0_forwarded_for.is_some() {
+        if let Some(header_value) = &request.auth0_forwarded_for {
             let mut headers = HeaderMap::new();
             let header_key = String::from("auth0-forwarded-for");
-            let header_value = &request.auth0_forwarded_for.as_ref().unwrap();
             headers.insert(
                 HeaderName::from_bytes(header_key.as_bytes()).unwrap(),
                 HeaderValue::from_bytes(header_value.as_bytes()).unwrap(),
             );
-            self.client.post(url).headers(headers).form(&request)
+            form.form(self.client.post(url).headers(headers))
         } else {
-            self.client.post(url).form(&request)
+            form.form(self.client.post(url))
         }
     }
 
@@ -104,14 +125,16 @@ impl GetToken for Api {
         let endpoint = String::from("/oauth/token");
         let url = self.base_url.join(&endpoint).unwrap();
 
-        self.client.post(url).form(&request)
+        self.client_authentication(GrantTypeParameters::new(GrantType::DeviceCode, &request))
+            .form(self.client.post(url))
     }
 
     fn refresh_token(&self, request: refresh_token::RequestParameters) -> RequestBuilder {
         let endpoint = String::from("/oauth/token");
         let url = self.base_url.join(&endpoint).unwrap();
 
-        self.client.post(url).form(&request)
+        self.client_authentication(GrantTypeParameters::new(GrantType::RefreshToken, &request))
+            .form(self.client.post(url))
     }
 
     fn token_exchange_for_native_social(
@@ -120,26 +143,83 @@ impl GetToken for Api {
     ) -> RequestBuilder {
         let endpoint = String::from("/oauth/token");
         let url = self.base_url.join(&endpoint).unwrap();
+        let form = self
+            .client_authentication(GrantTypeParameters::new(GrantType::TokenExchange, &request));
+
+        if let Some(header_value) = &request.auth0_forwarded_for {
+            let mut headers = HeaderMap::new();
+            let header_key = String::from("auth0-forwarded-for");
+            headers.insert(
+                HeaderName::from_bytes(header_key.as_bytes()).unwrap(),
+                HeaderValue::from_bytes(header_value.as_bytes()).unwrap(),
+            );
+            form.form(self.client.post(url).headers(headers))
+        } else {
+            form.form(self.client.post(url))
+        }
+    }
+
+    fn token_exchange(&self, request: token_exchange::RequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/oauth/token");
+        let url = self.base_url.join(&endpoint).unwrap();
+        let form = self
+            .client_authentication(GrantTypeParameters::new(GrantType::TokenExchange, &request));
 
-        if request.auth0_forwarded_for.is_some() {
+        if let Some(header_value) = &request.auth0_forwarded_for {
             let mut headers = HeaderMap::new();
             let header_key = String::from("auth0-forwarded-for");
-            let header_value = &request.auth0_forwarded_for.as_ref().unwrap();
             headers.insert(
                 HeaderName::from_bytes(header_key.as_bytes()).unwrap(),
                 HeaderValue::from_bytes(header_value.as_bytes()).unwrap(),
             );
-            self.client.post(url).headers(headers).form(&request)
+            form.form(self.client.post(url).headers(headers))
         } else {
-            self.client.post(url).form(&request)
+            form.form(self.client.post(url))
+        }
+    }
+}
+
+/// Prepends the `grant_type` an endpoint requires to its request parameters.
+#[derive(Serialize)]
+pub(crate) struct GrantTypeParameters<'a, T> {
+    grant_type: GrantType,
+    #[serde(flatten)]
+    request: &'a T,
+}
+
+impl<'a, T> GrantTypeParameters<'a, T> {
+    pub(crate) fn new(grant_type: GrantType, request: &'a T) -> Self {
+        GrantTypeParameters {
+            grant_type,
+            request,
         }
     }
 }
 
+impl<T: ClientCredentials> ClientCredentials for GrantTypeParameters<'_, T> {
+    fn client_credentials(&self) -> (Option<&str>, Option<&str>) {
+        self.request.client_credentials()
+    }
+}
+
+/// Tokens returned by `/oauth/token`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub token_type: Option<String>,
+    pub expires_in: Option<u64>,
+    pub scope: Option<String>,
+    pub id_token: Option<String>,
+    pub refresh_token: Option<String>,
+    /// Replacement recovery code, returned after verifying MFA with a recovery code.
+    pub recovery_code: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::authentication::*;
+    use crate::models::TokenType;
 
     #[test]
     fn authorization_code_flow_build_request() {
@@ -147,9 +227,8 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let get_token = Api::init(base_url, authentication);
         let parameters = get_token::authorization_code_flow::RequestParameters {
-            grant_type: String::from("some_awesome_grant"),
-            client_id: String::from("some_awesome_client_id"),
-            client_secret: String::from("some_awesome_client_secret"),
+            client_id: Some(String::from("some_awesome_client_id")),
+            client_secret: Some(String::from("some_awesome_client_secret")),
             code: String::from("some_awesome_code"),
             redirect_uri: None,
         };
@@ -159,7 +238,7 @@ mod tests {
             .unwrap();
         let test_url = String::from("https://your_domain/oauth/token");
         let test_body = String::from(
-            "grant_type=some_awesome_grant&\
+            "grant_type=authorization_code&\
             client_id=some_awesome_client_id&\
             client_secret=some_awesome_client_secret&\
             code=some_awesome_code",
@@ -179,8 +258,7 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let get_token = Api::init(base_url, authentication);
         let parameters = get_token::authorization_code_flow_with_pkce::RequestParameters {
-            grant_type: String::from("some_awesome_grant"),
-            client_id: String::from("some_awesome_client_id"),
+            client_id: Some(String::from("some_awesome_client_id")),
             code: String::from("some_awesome_code"),
             code_verifier: String::from("some_awesome_code_verifier"),
             redirect_uri: None,
@@ -191,7 +269,7 @@ mod tests {
             .unwrap();
         let test_url = String::from("https://your_domain/oauth/token");
         let test_body = String::from(
-            "grant_type=some_awesome_grant&\
+            "grant_type=authorization_code&\
             client_id=some_awesome_client_id&\
             code=some_awesome_code&\
             code_verifier=some_awesome_code_verifier",
@@ -211,9 +289,8 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let get_token = Api::init(base_url, authentication);
         let parameters = get_token::client_credentials_flow::RequestParameters {
-            grant_type: String::from("some_awesome_grant_type"),
-            client_id: String::from("some_awesome_client_id"),
-            client_secret: String::from("some_awesome_client_secret"),
+            client_id: Some(String::from("some_awesome_client_id")),
+            client_secret: Some(String::from("some_awesome_client_secret")),
             audience: String::from("some_awesome_audience_api"),
         };
         let request = get_token
@@ -222,7 +299,7 @@ mod tests {
             .unwrap();
         let test_url = String::from("https://your_domain/oauth/token");
         let test_body = String::from(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=client_credentials&\
             client_id=some_awesome_client_id&\
             client_secret=some_awesome_client_secret&\
             audience=some_awesome_audience_api",
@@ -242,8 +319,7 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let get_token = Api::init(base_url, authentication);
         let parameters = get_token::resource_owner_password::RequestParameters {
-            grant_type: String::from("some_awesome_grant_type"),
-            client_id: String::from("some_awesome_client_id"),
+            client_id: Some(String::from("some_awesome_client_id")),
             client_secret: None,
             audience: None,
             username: String::from("some_awesome_username"),
@@ -258,7 +334,7 @@ mod tests {
             .unwrap();
         let test_url = String::from("https://your_domain/oauth/token");
         let test_body = String::from(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=password&\
             client_id=some_awesome_client_id&\
             username=some_awesome_username&\
             password=some_awesome_password&\
@@ -279,8 +355,7 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let get_token = Api::init(base_url, authentication);
         let parameters = get_token::device_authorization_flow::RequestParameters {
-            grant_type: String::from("some_awesome_grant_type"),
-            client_id: String::from("some_awesome_client_id"),
+            client_id: Some(String::from("some_awesome_client_id")),
             device_code: String::from("some_awesome_device_code"),
         };
         let request = get_token
@@ -289,7 +364,7 @@ mod tests {
             .unwrap();
         let test_url = String::from("https://your_domain/oauth/token");
         let test_body = String::from(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Adevice_code&\
             client_id=some_awesome_client_id&\
             device_code=some_awesome_device_code",
         );
@@ -308,8 +383,7 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let get_token = Api::init(base_url, authentication);
         let parameters = get_token::refresh_token::RequestParameters {
-            grant_type: String::from("some_awesome_grant_type"),
-            client_id: String::from("some_awesome_client_id"),
+            client_id: Some(String::from("some_awesome_client_id")),
             client_secret: None,
             refresh_token: String::from("some_awesome_refresh_token"),
             scope: None,
@@ -317,7 +391,7 @@ mod tests {
         let request = get_token.refresh_token(parameters).build().unwrap();
         let test_url = String::from("https://your_domain/oauth/token");
         let test_body = String::from(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=refresh_token&\
             client_id=some_awesome_client_id&\
             refresh_token=some_awesome_refresh_token",
         );
@@ -336,10 +410,9 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let get_token = Api::init(base_url, authentication);
         let parameters = get_token::token_exchange_for_native_social::RequestParameters {
-            grant_type: String::from("some_awesome_grant_type"),
             subject_token: String::from("some_awesome_subject_token"),
             subject_token_type: String::from("some_awesome_subject_token_type"),
-            client_id: String::from("some_awesome_client_id"),
+            client_id: Some(String::from("some_awesome_client_id")),
             audience: None,
             scope: None,
             auth0_forwarded_for: None,
@@ -350,7 +423,7 @@ mod tests {
             .unwrap();
         let test_url = String::from("https://your_domain/oauth/token");
         let test_body = String::from(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Atoken-exchange&\
             subject_token=some_awesome_subject_token&\
             subject_token_type=some_awesome_subject_token_type&\
             client_id=some_awesome_client_id",
@@ -363,4 +436,45 @@ mod tests {
             test_body.as_bytes(),
         );
     }
+
+    #[test]
+    fn custom_token_exchange_build_request() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+        let get_token = Api::init(base_url, authentication);
+        let parameters = get_token::token_exchange::RequestParameters {
+            subject_token: String::from("some_awesome_subject_token"),
+            subject_token_type: TokenType::Other(String::from("urn:some-awesome-legacy-idp")),
+            client_id: Some(String::from("some_awesome_client_id")),
+            client_secret: Some(String::from("some_awesome_client_secret")),
+            actor_token: Some(String::from("some_awesome_actor_token")),
+            actor_token_type: Some(TokenType::AccessToken),
+            requested_token_type: Some(TokenType::RefreshToken),
+            audience: Some(String::from("some_awesome_audience")),
+            scope: Some(String::from("openid")),
+            organization: Some(String::from("some_awesome_organization")),
+            auth0_forwarded_for: None,
+        };
+        let request = get_token.token_exchange(parameters).build().unwrap();
+        let test_url = String::from("https://your_domain/oauth/token");
+        let test_body = String::from(
+            "grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Atoken-exchange&\
+            subject_token=some_awesome_subject_token&\
+            subject_token_type=urn%3Asome-awesome-legacy-idp&\
+            client_id=some_awesome_client_id&\
+            client_secret=some_awesome_client_secret&\
+            actor_token=some_awesome_actor_token&\
+            actor_token_type=urn%3Aietf%3Aparams%3Aoauth%3Atoken-type%3Aaccess_token&\
+            requested_token_type=urn%3Aietf%3Aparams%3Aoauth%3Atoken-type%3Arefresh_token&\
+            audience=some_awesome_audience&\
+            scope=openid&\
+            organization=some_awesome_organization",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
 }
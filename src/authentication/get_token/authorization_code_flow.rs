@@ -1,11 +1,19 @@
 use crate::authentication::get_token::*;
+use crate::authentication::ClientCredentials;
 
 #[derive(Serialize, Deserialize)]
 pub struct RequestParameters {
-    pub grant_type: String,
-    pub client_id: String,
-    pub client_secret: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
     pub code: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub redirect_uri: Option<String>,
 }
+
+impl ClientCredentials for RequestParameters {
+    fn client_credentials(&self) -> (Option<&str>, Option<&str>) {
+        (self.client_id.as_deref(), self.client_secret.as_deref())
+    }
+}
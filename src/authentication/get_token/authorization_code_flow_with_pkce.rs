@@ -1,11 +1,18 @@
 use crate::authentication::get_token::*;
+use crate::authentication::ClientCredentials;
 
 #[derive(Serialize, Deserialize)]
 pub struct RequestParameters {
-    pub grant_type: String,
-    pub client_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
     pub code: String,
     pub code_verifier: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub redirect_uri: Option<String>,
 }
+
+impl ClientCredentials for RequestParameters {
+    fn client_credentials(&self) -> (Option<&str>, Option<&str>) {
+        (self.client_id.as_deref(), None)
+    }
+}
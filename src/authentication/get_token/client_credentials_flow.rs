@@ -1,13 +1,21 @@
 pub use crate::authentication::get_token::*;
+use crate::authentication::ClientCredentials;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RequestParameters {
-    pub grant_type: String,
-    pub client_id: String,
-    pub client_secret: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
     pub audience: String,
 }
 
+impl ClientCredentials for RequestParameters {
+    fn client_credentials(&self) -> (Option<&str>, Option<&str>) {
+        (self.client_id.as_deref(), self.client_secret.as_deref())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Response {
     pub access_token: String,
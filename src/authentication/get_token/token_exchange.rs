@@ -0,0 +1,44 @@
+use crate::authentication::get_token::*;
+use crate::authentication::ClientCredentials;
+use crate::models::TokenType;
+
+#[derive(Serialize, Deserialize)]
+pub struct RequestParameters {
+    pub subject_token: String,
+    pub subject_token_type: TokenType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor_token_type: Option<TokenType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_token_type: Option<TokenType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+    #[serde(skip_serializing)]
+    pub auth0_forwarded_for: Option<String>,
+}
+
+impl ClientCredentials for RequestParameters {
+    fn client_credentials(&self) -> (Option<&str>, Option<&str>) {
+        (self.client_id.as_deref(), self.client_secret.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Response {
+    pub access_token: String,
+    pub issued_token_type: TokenType,
+    pub token_type: String,
+    pub expires_in: Option<u64>,
+    pub scope: Option<String>,
+    pub id_token: Option<String>,
+    pub refresh_token: Option<String>,
+}
@@ -0,0 +1,150 @@
+//! Verification of ID tokens and validation of the claims that bind them to a response.
+
+use std::collections::HashMap;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+use crate::error::IdTokenError;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Header {
+    pub alg: String,
+    pub kid: Option<String>,
+    pub typ: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claims {
+    pub iss: String,
+    pub sub: Option<String>,
+    /// A single audience or an array of audiences.
+    pub aud: serde_json::Value,
+    pub exp: Option<u64>,
+    pub iat: Option<u64>,
+    pub nonce: Option<String>,
+    pub at_hash: Option<String>,
+    pub c_hash: Option<String>,
+    pub sid: Option<String>,
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+/// Verifies the signature of an ID token against the tenant's signing keys, along with its
+/// issuer, audience and expiry, and returns its header and claims.
+pub fn verify(
+    token: &str,
+    jwks: &JwkSet,
+    issuer: &str,
+    audience: &str,
+) -> Result<(Header, Claims), IdTokenError> {
+    let header = jsonwebtoken::decode_header(token)?;
+    if let Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 = header.alg {
+        return Err(jsonwebtoken::errors::Error::from(
+            jsonwebtoken::errors::ErrorKind::InvalidAlgorithm,
+        )
+        .into());
+    }
+    let jwk = signing_key(jwks, header.kid.as_deref())
+        .ok_or_else(|| IdTokenError::UnknownKey(header.kid.clone()))?;
+    let key = DecodingKey::from_jwk(jwk)?;
+
+    let mut validation = Validation::new(header.alg);
+    validation.set_issuer(&[issuer]);
+    validation.set_audience(&[audience]);
+    validation.set_required_spec_claims(&["iss", "aud", "exp"]);
+    let claims = jsonwebtoken::decode::<Claims>(token, &key, &validation)?.claims;
+    Ok((decode(token)?.0, claims))
+}
+
+/// Finds the key a token was signed with. A token without a `kid` can only match a set holding
+/// a single key.
+pub(crate) fn signing_key<'a>(jwks: &'a JwkSet, kid: Option<&str>) -> Option<&'a Jwk> {
+    match kid {
+        Some(kid) => jwks.find(kid),
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    }
+}
+
+/// Decodes the header and claims of a JWT without verifying its signature. Only use the result
+/// for tokens received directly from Auth0, e.g. to read the expiry of an access token; use
+/// [`verify`] for ID tokens passed through the user agent.
+pub fn decode(token: &str) -> Result<(Header, Claims), IdTokenError> {
+    let mut parts = token.split('.');
+    let (header, claims) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(header), Some(claims), Some(_), None) => (header, claims),
+        _ => return Err(IdTokenError::Malformed),
+    };
+    Ok((decode_part(header)?, decode_part(claims)?))
+}
+
+fn decode_part<T: serde::de::DeserializeOwned>(part: &str) -> Result<T, IdTokenError> {
+    let json = URL_SAFE_NO_PAD
+        .decode(part)
+        .map_err(|_| IdTokenError::Malformed)?;
+    serde_json::from_slice(&json).map_err(|_| IdTokenError::Malformed)
+}
+
+/// Computes an `at_hash`/`c_hash` value: the left half of the hash of `value`, using the hash
+/// function of the token's signing algorithm.
+pub fn left_half_hash(alg: &str, value: &str) -> Result<String, IdTokenError> {
+    let digest = match alg {
+        "RS256" | "HS256" | "ES256" | "PS256" => Sha256::digest(value.as_bytes()).to_vec(),
+        "RS384" | "HS384" | "ES384" | "PS384" => Sha384::digest(value.as_bytes()).to_vec(),
+        "RS512" | "HS512" | "ES512" | "PS512" => Sha512::digest(value.as_bytes()).to_vec(),
+        _ => return Err(IdTokenError::UnsupportedAlgorithm(alg.to_string())),
+    };
+    Ok(URL_SAFE_NO_PAD.encode(&digest[..digest.len() / 2]))
+}
+
+/// Checks that `claims` carries the expected nonce.
+pub fn validate_nonce(claims: &Claims, nonce: &str) -> Result<(), IdTokenError> {
+    match &claims.nonce {
+        Some(claim) if claim == nonce => Ok(()),
+        _ => Err(IdTokenError::NonceMismatch),
+    }
+}
+
+/// Checks an `at_hash`/`c_hash` claim against the value returned alongside the ID token.
+pub fn validate_hash(
+    alg: &str,
+    claim: Option<&String>,
+    value: &str,
+    required: bool,
+    name: &str,
+) -> Result<(), IdTokenError> {
+    match claim {
+        Some(claim) if *claim == left_half_hash(alg, value)? => Ok(()),
+        Some(_) => Err(IdTokenError::HashMismatch(name.to_string())),
+        None if required => Err(IdTokenError::MissingClaim(name.to_string())),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn left_half_hash_rejects_unknown_algorithm() {
+        assert!(matches!(
+            left_half_hash("€a", "some_awesome_access_token"),
+            Err(IdTokenError::UnsupportedAlgorithm(_))
+        ));
+        assert!(matches!(
+            left_half_hash("none", "some_awesome_access_token"),
+            Err(IdTokenError::UnsupportedAlgorithm(_))
+        ));
+        assert_eq!(
+            left_half_hash("RS256", "some_awesome_access_token")
+                .unwrap()
+                .len(),
+            22
+        );
+    }
+}
@@ -19,9 +19,11 @@ impl Login for Api {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
     use crate::authentication::*;
+    use crate::models::ResponseType;
 
     #[test]
     fn enterprise_build_request() {
@@ -29,7 +31,7 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let login = Api::init(base_url, authentication);
         let parameters = login::enterprise::RequestParameters {
-            response_type: String::from("some_awesome_response_type"),
+            response_type: ResponseType::Code,
             client_id: String::from("some_awesome_client_id"),
             connection: None,
             redirect_uri: String::from("some_awesome_redirect_uri"),
@@ -37,7 +39,7 @@ mod tests {
         };
         let request = login.authorize(parameters).build().unwrap();
         let test_url =
-            String::from("https://your_domain/authorize?response_type=some_awesome_response_type&client_id=some_awesome_client_id&redirect_uri=some_awesome_redirect_uri");
+            String::from("https://your_domain/authorize?response_type=code&client_id=some_awesome_client_id&redirect_uri=some_awesome_redirect_uri");
         assert_eq!(request.method().as_str(), reqwest::Method::GET);
         assert_eq!(request.url().as_str(), test_url);
         assert_eq!(request.headers().is_empty(), true);
@@ -50,7 +52,7 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let login = Api::init(base_url, authentication);
         let parameters = login::passive::RequestParameters {
-            response_type: String::from("some_awesome_response_type"),
+            response_type: ResponseType::Code,
             client_id: String::from("some_awesome_client_id"),
             connection: None,
             redirect_uri: String::from("some_awesome_redirect_uri"),
@@ -59,7 +61,7 @@ mod tests {
         };
         let request = login.authorize(parameters).build().unwrap();
         let test_url =
-            String::from("https://your_domain/authorize?response_type=some_awesome_response_type&client_id=some_awesome_client_id&redirect_uri=some_awesome_redirect_uri&state=some_awesome_state");
+            String::from("https://your_domain/authorize?response_type=code&client_id=some_awesome_client_id&redirect_uri=some_awesome_redirect_uri&state=some_awesome_state");
         assert_eq!(request.method().as_str(), reqwest::Method::GET);
         assert_eq!(request.url().as_str(), test_url);
         assert_eq!(request.headers().is_empty(), true);
@@ -72,7 +74,7 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let login = Api::init(base_url, authentication);
         let parameters = login::social::RequestParameters {
-            response_type: String::from("some_awesome_response_type"),
+            response_type: ResponseType::Code,
             client_id: String::from("some_awesome_client_id"),
             connection: None,
             redirect_uri: String::from("some_awesome_redirect_uri"),
@@ -81,7 +83,7 @@ mod tests {
         };
         let request = login.authorize(parameters).build().unwrap();
         let test_url =
-            String::from("https://your_domain/authorize?response_type=some_awesome_response_type&client_id=some_awesome_client_id&redirect_uri=some_awesome_redirect_uri");
+            String::from("https://your_domain/authorize?response_type=code&client_id=some_awesome_client_id&redirect_uri=some_awesome_redirect_uri");
         assert_eq!(request.method().as_str(), reqwest::Method::GET);
         assert_eq!(request.url().as_str(), test_url);
         assert_eq!(request.headers().is_empty(), true);
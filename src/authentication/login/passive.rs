@@ -1,8 +1,9 @@
 use crate::authentication::login::*;
+use crate::models::ResponseType;
 
 #[derive(Serialize, Deserialize)]
 pub struct RequestParameters {
-    pub response_type: String,
+    pub response_type: ResponseType,
     pub client_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub connection: Option<String>,
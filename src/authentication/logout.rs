@@ -13,8 +13,28 @@ pub struct RequestParameters {
     pub federated: Option<String>,
 }
 
+/// Parameters of the OIDC RP-Initiated Logout endpoint.
+#[derive(Serialize, Deserialize)]
+pub struct OidcLogoutRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_token_hint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logout_hint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_logout_redirect_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub federated: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ui_locales: Option<String>,
+}
+
 pub trait Logout {
     fn logout(&self, request: RequestParameters) -> RequestBuilder;
+    fn oidc_logout(&self, request: OidcLogoutRequestParameters) -> RequestBuilder;
 }
 
 impl Logout for Api {
@@ -23,9 +43,16 @@ impl Logout for Api {
         let url = self.base_url.join(&endpoint).unwrap();
         self.client.get(url).query(&request)
     }
+
+    fn oidc_logout(&self, request: OidcLogoutRequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/oidc/logout");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.client.get(url).query(&request)
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
     use crate::authentication::*;
@@ -48,4 +75,27 @@ mod tests {
         assert_eq!(request.headers().is_empty(), true);
         assert_eq!(request.body().is_none(), true);
     }
+
+    #[test]
+    fn oidc_logout_build_request() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+        let logout = Api::init(base_url, authentication);
+        let parameters = logout::OidcLogoutRequestParameters {
+            id_token_hint: Some(String::from("some_awesome_id_token")),
+            logout_hint: None,
+            client_id: None,
+            post_logout_redirect_uri: Some(String::from("some_awesome_redirect_uri")),
+            state: Some(String::from("some_awesome_state")),
+            federated: None,
+            ui_locales: None,
+        };
+        let request = logout.oidc_logout(parameters).build().unwrap();
+        let test_url =
+            String::from("https://your_domain/oidc/logout?id_token_hint=some_awesome_id_token&post_logout_redirect_uri=some_awesome_redirect_uri&state=some_awesome_state");
+        assert_eq!(request.method().as_str(), reqwest::Method::GET);
+        assert_eq!(request.url().as_str(), test_url);
+        assert!(request.headers().is_empty());
+        assert!(request.body().is_none());
+    }
 }
@@ -1,4 +1,6 @@
+use crate::authentication::get_token::GrantTypeParameters;
 use crate::authentication::Api;
+use crate::models::GrantType;
 use reqwest::header::{HeaderMap, HeaderValue};
 use reqwest::RequestBuilder;
 pub use serde::{Deserialize, Serialize};
@@ -10,6 +12,7 @@ pub mod list_authenticators;
 pub mod one_time_password;
 pub mod out_of_band;
 pub mod recovery_code;
+pub mod session;
 
 pub trait MultiFactorAuthentication {
     fn challenge_request(&self, request: challenge_request::RequestParameters) -> RequestBuilder;
@@ -34,17 +37,20 @@ impl MultiFactorAuthentication for Api {
     fn challenge_request(&self, request: challenge_request::RequestParameters) -> RequestBuilder {
         let endpoint = String::from("/mfa/challenge");
         let url = self.base_url.join(&endpoint).unwrap();
-        self.client.post(url).json(&request)
+        self.client_authentication(&request)
+            .json(self.client.post(url))
     }
     fn verify_with_otp(&self, request: one_time_password::RequestParameters) -> RequestBuilder {
         let endpoint = String::from("/oauth/token");
         let url = self.base_url.join(&endpoint).unwrap();
-        self.client.post(url).form(&request)
+        self.client_authentication(GrantTypeParameters::new(GrantType::MfaOtp, &request))
+            .form(self.client.post(url))
     }
     fn verify_with_oob(&self, request: out_of_band::RequestParameters) -> RequestBuilder {
         let endpoint = String::from("/oauth/token");
         let url = self.base_url.join(&endpoint).unwrap();
-        self.client.post(url).form(&request)
+        self.client_authentication(GrantTypeParameters::new(GrantType::MfaOob, &request))
+            .form(self.client.post(url))
     }
     fn verify_with_recovery_code(
         &self,
@@ -52,12 +58,17 @@ impl MultiFactorAuthentication for Api {
     ) -> RequestBuilder {
         let endpoint = String::from("/oauth/token");
         let url = self.base_url.join(&endpoint).unwrap();
-        self.client.post(url).form(&request)
+        self.client_authentication(GrantTypeParameters::new(
+            GrantType::MfaRecoveryCode,
+            &request,
+        ))
+        .form(self.client.post(url))
     }
     fn add_authenticator(&self, request: add_authenticator::RequestParameters) -> RequestBuilder {
         let endpoint = String::from("/mfa/associate");
         let url = self.base_url.join(&endpoint).unwrap();
-        self.client.post(url).json(&request)
+        self.client_authentication(&request)
+            .json(self.client.post(url))
     }
     fn list_authenticators(
         &self,
@@ -101,9 +112,11 @@ impl MultiFactorAuthentication for Api {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
     use crate::authentication::*;
+    use crate::models::AuthenticatorType;
 
     #[test]
     fn challenge_build_request() {
@@ -112,7 +125,7 @@ mod tests {
         let mfa = Api::init(base_url, authentication);
         let parameters = mfa::challenge_request::RequestParameters {
             mfa_token: String::from("some_awesome_mfa_token"),
-            client_id: String::from("some_awesome_client_id"),
+            client_id: Some(String::from("some_awesome_client_id")),
             client_secret: None,
             challenge_type: None,
             oob_channel: None,
@@ -139,8 +152,7 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let mfa = Api::init(base_url, authentication);
         let parameters = mfa::one_time_password::RequestParameters {
-            grant_type: String::from("some_awesome_grant_type"),
-            client_id: String::from("some_awesome_client_id"),
+            client_id: Some(String::from("some_awesome_client_id")),
             client_secret: None,
             mfa_token: String::from("some_awesome_mfa_token"),
             otp: String::from("some_awesome_otp"),
@@ -148,7 +160,7 @@ mod tests {
         let request = mfa.verify_with_otp(parameters).build().unwrap();
         let test_url = String::from("https://your_domain/oauth/token");
         let test_body = String::from(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=http%3A%2F%2Fauth0.com%2Foauth%2Fgrant-type%2Fmfa-otp&\
             client_id=some_awesome_client_id&\
             mfa_token=some_awesome_mfa_token&\
             otp=some_awesome_otp",
@@ -168,8 +180,7 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let mfa = Api::init(base_url, authentication);
         let parameters = mfa::out_of_band::RequestParameters {
-            grant_type: String::from("some_awesome_grant_type"),
-            client_id: String::from("some_awesome_client_id"),
+            client_id: Some(String::from("some_awesome_client_id")),
             client_secret: None,
             mfa_token: String::from("some_awesome_mfa_token"),
             oob_code: String::from("some_awesome_oob_code"),
@@ -178,7 +189,7 @@ mod tests {
         let request = mfa.verify_with_oob(parameters).build().unwrap();
         let test_url = String::from("https://your_domain/oauth/token");
         let test_body = String::from(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=http%3A%2F%2Fauth0.com%2Foauth%2Fgrant-type%2Fmfa-oob&\
             client_id=some_awesome_client_id&\
             mfa_token=some_awesome_mfa_token&\
             oob_code=some_awesome_oob_code",
@@ -198,8 +209,7 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let mfa = Api::init(base_url, authentication);
         let parameters = mfa::recovery_code::RequestParameters {
-            grant_type: String::from("some_awesome_grant_type"),
-            client_id: String::from("some_awesome_client_id"),
+            client_id: Some(String::from("some_awesome_client_id")),
             client_secret: None,
             mfa_token: String::from("some_awesome_mfa_token"),
             recovery_code: String::from("some_awesome_mfa_token"),
@@ -207,7 +217,7 @@ mod tests {
         let request = mfa.verify_with_recovery_code(parameters).build().unwrap();
         let test_url = String::from("https://your_domain/oauth/token");
         let test_body = String::from(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=http%3A%2F%2Fauth0.com%2Foauth%2Fgrant-type%2Fmfa-recovery-code&\
             client_id=some_awesome_client_id&\
             mfa_token=some_awesome_mfa_token&\
             recovery_code=some_awesome_mfa_token",
@@ -227,17 +237,17 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let mfa = Api::init(base_url, authentication);
         let parameters = mfa::add_authenticator::RequestParameters {
-            client_id: String::from("some_awesome_client_id"),
+            client_id: Some(String::from("some_awesome_client_id")),
             client_secret: None,
-            authenticator_types: String::from("some_awesome_authenticator_type"),
-            oob_channel: None,
+            authenticator_types: vec![AuthenticatorType::Otp],
+            oob_channels: None,
             phone_number: None,
         };
         let request = mfa.add_authenticator(parameters).build().unwrap();
         let test_url = String::from("https://your_domain/mfa/associate");
         let test_body = String::from(
             "{\"client_id\":\"some_awesome_client_id\",\
-            \"authenticator_types\":\"some_awesome_authenticator_type\"}",
+            \"authenticator_types\":[\"otp\"]}",
         );
         assert_eq!(request.method().as_str(), reqwest::Method::POST);
         assert_eq!(request.url().as_str(), test_url);
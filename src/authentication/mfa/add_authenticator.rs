@@ -1,13 +1,22 @@
 use crate::authentication::mfa::*;
+use crate::authentication::ClientCredentials;
+use crate::models::{AuthenticatorType, OobChannel};
 
 #[derive(Serialize, Deserialize)]
 pub struct RequestParameters {
-    pub client_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_secret: Option<String>,
-    pub authenticator_types: String,
+    pub authenticator_types: Vec<AuthenticatorType>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub oob_channel: Option<String>,
+    pub oob_channels: Option<Vec<OobChannel>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub phone_number: Option<String>,
 }
+
+impl ClientCredentials for RequestParameters {
+    fn client_credentials(&self) -> (Option<&str>, Option<&str>) {
+        (self.client_id.as_deref(), self.client_secret.as_deref())
+    }
+}
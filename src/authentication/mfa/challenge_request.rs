@@ -1,15 +1,31 @@
 use crate::authentication::mfa::*;
+use crate::authentication::ClientCredentials;
+use crate::models::{ChallengeType, ChallengeTypes, OobChannel};
 
 #[derive(Serialize, Deserialize)]
 pub struct RequestParameters {
     pub mfa_token: String,
-    pub client_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_secret: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub challenge_type: Option<Vec<String>>,
+    pub challenge_type: Option<ChallengeTypes>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub oob_channel: Option<String>,
+    pub oob_channel: Option<OobChannel>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub authenticator_id: Option<String>,
 }
+
+impl ClientCredentials for RequestParameters {
+    fn client_credentials(&self) -> (Option<&str>, Option<&str>) {
+        (self.client_id.as_deref(), self.client_secret.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Response {
+    pub challenge_type: ChallengeType,
+    pub oob_code: Option<String>,
+    pub binding_method: Option<String>,
+}
@@ -1,6 +1,16 @@
 use crate::authentication::mfa::*;
+use crate::models::{AuthenticatorType, OobChannel};
 
 #[derive(Serialize, Deserialize)]
 pub struct RequestParameters {
     pub access_token: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Authenticator {
+    pub id: String,
+    pub authenticator_type: AuthenticatorType,
+    pub active: bool,
+    pub oob_channel: Option<OobChannel>,
+    pub name: Option<String>,
+}
@@ -0,0 +1,174 @@
+//! Step-up flow for logins that fail with `mfa_required`.
+//!
+//! ```ignore
+//! // `error` is the `AuthenticationError` of a failed token request.
+//! let session = MfaSession::from_error(&api, &error, client_id, client_secret).ok_or(error)?;
+//! let authenticator = session.authenticators().await?.remove(0);
+//! let challenge = session.challenge(&authenticator).await?;
+//! let token = session.poll_oob(&challenge, Duration::from_secs(5)).await?;
+//! ```
+
+use std::time::Duration;
+
+use crate::authentication::get_token::TokenResponse;
+use crate::authentication::mfa::*;
+use crate::error::{AuthenticationError, Error};
+
+/// Additional delay requested by a `slow_down` error.
+const SLOW_DOWN_INCREMENT: Duration = Duration::from_secs(5);
+
+pub struct MfaSession<'a> {
+    api: &'a Api,
+    mfa_token: String,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+}
+
+impl<'a> MfaSession<'a> {
+    pub fn new(
+        api: &'a Api,
+        mfa_token: String,
+        client_id: Option<String>,
+        client_secret: Option<String>,
+    ) -> Self {
+        MfaSession {
+            api,
+            mfa_token,
+            client_id,
+            client_secret,
+        }
+    }
+
+    /// Starts a session from the error of a token request, if it is `mfa_required`. Without a
+    /// `client_id`, the credentials configured on `api` are used.
+    pub fn from_error(
+        api: &'a Api,
+        error: &AuthenticationError,
+        client_id: Option<String>,
+        client_secret: Option<String>,
+    ) -> Option<Self> {
+        match error {
+            AuthenticationError::MfaRequired { mfa_token, .. } => Some(MfaSession::new(
+                api,
+                mfa_token.clone(),
+                client_id,
+                client_secret,
+            )),
+            _ => None,
+        }
+    }
+
+    pub fn mfa_token(&self) -> &str {
+        &self.mfa_token
+    }
+
+    /// Lists the user's active authenticators.
+    pub async fn authenticators(&self) -> Result<Vec<list_authenticators::Authenticator>, Error> {
+        let request = self
+            .api
+            .list_authenticators(list_authenticators::RequestParameters {
+                access_token: self.mfa_token.clone(),
+            });
+        let authenticators = self
+            .api
+            .send(request)
+            .await?
+            .json::<Vec<list_authenticators::Authenticator>>()
+            .await?;
+        Ok(authenticators
+            .into_iter()
+            .filter(|authenticator| authenticator.active)
+            .collect())
+    }
+
+    /// Challenges an authenticator, e.g. sending a push notification or SMS.
+    pub async fn challenge(
+        &self,
+        authenticator: &list_authenticators::Authenticator,
+    ) -> Result<challenge_request::Response, Error> {
+        let request = self
+            .api
+            .challenge_request(challenge_request::RequestParameters {
+                mfa_token: self.mfa_token.clone(),
+                client_id: self.client_id.clone(),
+                client_secret: self.client_secret.clone(),
+                challenge_type: None,
+                oob_channel: None,
+                authenticator_id: Some(authenticator.id.clone()),
+            });
+        Ok(self
+            .api
+            .send(request)
+            .await?
+            .json::<challenge_request::Response>()
+            .await?)
+    }
+
+    pub async fn verify_otp(&self, otp: String) -> Result<TokenResponse, Error> {
+        let request = self
+            .api
+            .verify_with_otp(one_time_password::RequestParameters {
+                client_id: self.client_id.clone(),
+                client_secret: self.client_secret.clone(),
+                mfa_token: self.mfa_token.clone(),
+                otp,
+            });
+        Ok(self.api.send(request).await?.json().await?)
+    }
+
+    /// Verifies an out-of-band challenge once; fails with `authorization_pending` while the
+    /// user hasn't approved it yet. See [`MfaSession::poll_oob`].
+    pub async fn verify_oob(
+        &self,
+        oob_code: String,
+        binding_code: Option<String>,
+    ) -> Result<TokenResponse, Error> {
+        let request = self.api.verify_with_oob(out_of_band::RequestParameters {
+            client_id: self.client_id.clone(),
+            client_secret: self.client_secret.clone(),
+            mfa_token: self.mfa_token.clone(),
+            oob_code,
+            binding_code,
+        });
+        Ok(self.api.send(request).await?.json().await?)
+    }
+
+    /// Polls an out-of-band (push) challenge until the user approves or rejects it. Fails with
+    /// [`Error::MissingParameter`] if the challenge has no `oob_code`, e.g. an OTP challenge.
+    pub async fn poll_oob(
+        &self,
+        challenge: &challenge_request::Response,
+        interval: Duration,
+    ) -> Result<TokenResponse, Error> {
+        let oob_code = challenge
+            .oob_code
+            .clone()
+            .ok_or_else(|| Error::MissingParameter(String::from("oob_code")))?;
+        let mut interval = interval;
+        loop {
+            match self.verify_oob(oob_code.clone(), None).await {
+                Err(Error::AuthenticationError(AuthenticationError::AuthorizationPending(_))) => {}
+                Err(Error::AuthenticationError(AuthenticationError::SlowDown(_))) => {
+                    interval += SLOW_DOWN_INCREMENT;
+                }
+                result => return result,
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    pub async fn verify_recovery_code(
+        &self,
+        recovery_code: String,
+    ) -> Result<TokenResponse, Error> {
+        let request = self
+            .api
+            .verify_with_recovery_code(recovery_code::RequestParameters {
+                client_id: self.client_id.clone(),
+                client_secret: self.client_secret.clone(),
+                mfa_token: self.mfa_token.clone(),
+                recovery_code,
+            });
+        Ok(self.api.send(request).await?.json().await?)
+    }
+}
@@ -1,4 +1,6 @@
+use crate::authentication::get_token::GrantTypeParameters;
 use crate::authentication::Api;
+use crate::models::GrantType;
 use reqwest::RequestBuilder;
 use serde::{Deserialize, Serialize};
 
@@ -14,13 +16,18 @@ impl Passwordless for Api {
     fn passwordless_start(&self, request: get_code_or_link::RequestParameters) -> RequestBuilder {
         let endpoint = String::from("/passwordless/start");
         let url = self.base_url.join(&endpoint).unwrap();
-        self.client.post(url).json(&request)
+        self.client_authentication(&request)
+            .json(self.client.post(url))
     }
 
     fn passwordless_login(&self, request: authenticate_user::RequestParameters) -> RequestBuilder {
         let endpoint = String::from("/oauth/token");
         let url = self.base_url.join(&endpoint).unwrap();
-        self.client.post(url).json(&request)
+        self.client_authentication(GrantTypeParameters::new(
+            GrantType::PasswordlessOtp,
+            &request,
+        ))
+        .json(self.client.post(url))
     }
 }
 
@@ -35,8 +42,8 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let passwordless = Api::init(base_url, authentication);
         let parameters = passwordless::get_code_or_link::RequestParameters {
-            client_id: String::from("some_awesome_client_id"),
-            client_secret: String::from("some_awesome_client_secret"),
+            client_id: Some(String::from("some_awesome_client_id")),
+            client_secret: Some(String::from("some_awesome_client_secret")),
             connection: String::from("some_awesome_connection"),
             email: Some(String::from("tester@awesome.com")),
             phone_number: None,
@@ -67,9 +74,8 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let passwordless = Api::init(base_url, authentication);
         let parameters = passwordless::authenticate_user::RequestParameters {
-            grant_type: String::from("some_awesome_grant_type"),
-            client_id: String::from("some_awesome_client_id"),
-            client_secret: String::from("some_awesome_client_secret"),
+            client_id: Some(String::from("some_awesome_client_id")),
+            client_secret: Some(String::from("some_awesome_client_secret")),
             username: String::from("some_awesome_username"),
             realm: String::from("some_awesome_realm"),
             otp: String::from("some_awesome_otp"),
@@ -79,7 +85,7 @@ mod tests {
         let request = passwordless.passwordless_login(parameters).build().unwrap();
         let test_url = String::from("https://your_domain/oauth/token");
         let test_body = String::from(
-            "{\"grant_type\":\"some_awesome_grant_type\",\
+            "{\"grant_type\":\"http://auth0.com/oauth/grant-type/passwordless/otp\",\
             \"client_id\":\"some_awesome_client_id\",\
             \"client_secret\":\"some_awesome_client_secret\",\
             \"username\":\"some_awesome_username\",\
@@ -0,0 +1,303 @@
+use crate::authentication::authorize_application::authorize_url::{
+    random_string, requests_id_token, AuthorizeUrl,
+};
+use crate::authentication::{Api, ClientCredentials};
+use crate::error::Error;
+use crate::models::ResponseType;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct RequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    pub response_type: ResponseType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_challenge_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_challenge: Option<String>,
+}
+
+impl ClientCredentials for RequestParameters {
+    fn client_credentials(&self) -> (Option<&str>, Option<&str>) {
+        (self.client_id.as_deref(), self.client_secret.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Response {
+    pub request_uri: String,
+    pub expires_in: u64,
+}
+
+/// A pushed authorization request ready to be sent, and the parameters it carries.
+pub struct PushedRequest {
+    pub request: RequestBuilder,
+    /// The pushed parameters: `state` is always set, and `nonce` is set when an ID token is
+    /// requested. Keep them to build the
+    /// [`pushed_authorize_url`](PushedAuthorizationRequest::pushed_authorize_url).
+    pub parameters: RequestParameters,
+}
+
+pub trait PushedAuthorizationRequest {
+    /// Pushes the authorization request. A random `state` is generated when `request` has none,
+    /// and a `nonce` when an ID token is requested.
+    fn pushed_authorization_request(&self, request: RequestParameters) -> PushedRequest;
+
+    /// `/authorize` URL referencing a pushed request. The returned [`AuthorizeUrl`] validates
+    /// the callback against the pushed `state` and `nonce`.
+    ///
+    /// Fails with [`Error::MissingParameter`] when neither `request` nor the client has a
+    /// `client_id`, or when `request` has no `state`.
+    #[allow(clippy::result_large_err)]
+    fn pushed_authorize_url(
+        &self,
+        request: &RequestParameters,
+        response: &Response,
+    ) -> Result<AuthorizeUrl, Error>;
+}
+
+impl PushedAuthorizationRequest for Api {
+    fn pushed_authorization_request(&self, mut request: RequestParameters) -> PushedRequest {
+        let endpoint = String::from("/oauth/par");
+        let url = self.base_url.join(&endpoint).unwrap();
+
+        request.state.get_or_insert_with(random_string);
+        if request.nonce.is_none()
+            && requests_id_token(request.response_type, request.scope.as_deref())
+        {
+            request.nonce = Some(random_string());
+        }
+        PushedRequest {
+            request: self
+                .client_authentication(&request)
+                .form(self.client.post(url)),
+            parameters: request,
+        }
+    }
+
+    fn pushed_authorize_url(
+        &self,
+        request: &RequestParameters,
+        response: &Response,
+    ) -> Result<AuthorizeUrl, Error> {
+        let endpoint = String::from("/authorize");
+        let mut url = self.base_url.join(&endpoint).unwrap();
+        let client_id = request
+            .client_id
+            .clone()
+            .or_else(|| self.client_id().map(String::from))
+            .ok_or_else(|| Error::MissingParameter(String::from("client_id")))?;
+        let state = request
+            .state
+            .clone()
+            .ok_or_else(|| Error::MissingParameter(String::from("state")))?;
+        url.query_pairs_mut()
+            .append_pair("client_id", &client_id)
+            .append_pair("request_uri", &response.request_uri);
+
+        Ok(AuthorizeUrl {
+            url,
+            state,
+            nonce: request.nonce.clone(),
+            // The `code_verifier` of a pushed `code_challenge` stays with the caller.
+            code_verifier: None,
+            client_id,
+            redirect_uri: request.redirect_uri.clone(),
+            response_type: request.response_type,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authentication::*;
+
+    #[test]
+    fn pushed_authorization_request_build_request() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+        let pushed_authorization_request = Api::init(base_url, authentication);
+        let parameters = pushed_authorization_request::RequestParameters {
+            client_id: Some(String::from("some_awesome_client_id")),
+            client_secret: Some(String::from("some_awesome_client_secret")),
+            response_type: ResponseType::Code,
+            redirect_uri: Some(String::from("some_awesome_redirect_uri")),
+            audience: None,
+            scope: Some(String::from("openid")),
+            state: Some(String::from("some_awesome_state")),
+            nonce: Some(String::from("some_awesome_nonce")),
+            connection: None,
+            organization: None,
+            prompt: None,
+            code_challenge_method: Some(String::from("S256")),
+            code_challenge: Some(String::from("some_awesome_code_challenge")),
+        };
+        let request = pushed_authorization_request
+            .pushed_authorization_request(parameters)
+            .request
+            .build()
+            .unwrap();
+        let test_url = String::from("https://your_domain/oauth/par");
+        let test_body = String::from(
+            "client_id=some_awesome_client_id&\
+            client_secret=some_awesome_client_secret&\
+            response_type=code&\
+            redirect_uri=some_awesome_redirect_uri&\
+            scope=openid&\
+            state=some_awesome_state&\
+            nonce=some_awesome_nonce&\
+            code_challenge_method=S256&\
+            code_challenge=some_awesome_code_challenge",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[test]
+    fn pushed_authorize_url_build() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+        let pushed_authorization_request = Api::init(base_url, authentication);
+        let parameters = pushed_authorization_request::RequestParameters {
+            client_id: Some(String::from("some_awesome_client_id")),
+            client_secret: None,
+            response_type: ResponseType::Code,
+            redirect_uri: None,
+            audience: None,
+            scope: None,
+            state: Some(String::from("some_awesome_state")),
+            nonce: None,
+            connection: None,
+            organization: None,
+            prompt: None,
+            code_challenge_method: None,
+            code_challenge: None,
+        };
+        let response = pushed_authorization_request::Response {
+            request_uri: String::from("urn:ietf:params:oauth:request_uri:some_awesome_id"),
+            expires_in: 30,
+        };
+        let authorize_url = pushed_authorization_request
+            .pushed_authorize_url(&parameters, &response)
+            .unwrap();
+        let test_url = String::from(
+            "https://your_domain/authorize?\
+            client_id=some_awesome_client_id&\
+            request_uri=urn%3Aietf%3Aparams%3Aoauth%3Arequest_uri%3Asome_awesome_id",
+        );
+        assert_eq!(authorize_url.url.as_str(), test_url);
+        assert_eq!(authorize_url.state, "some_awesome_state");
+    }
+
+    #[test]
+    fn pushed_authorize_url_requires_client_id() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+        let pushed_authorization_request = Api::init(base_url, authentication);
+        let parameters = pushed_authorization_request::RequestParameters {
+            client_id: None,
+            client_secret: None,
+            response_type: ResponseType::Code,
+            redirect_uri: None,
+            audience: None,
+            scope: None,
+            state: Some(String::from("some_awesome_state")),
+            nonce: None,
+            connection: None,
+            organization: None,
+            prompt: None,
+            code_challenge_method: None,
+            code_challenge: None,
+        };
+        let response = pushed_authorization_request::Response {
+            request_uri: String::from("urn:ietf:params:oauth:request_uri:some_awesome_id"),
+            expires_in: 30,
+        };
+        assert!(matches!(
+            pushed_authorization_request.pushed_authorize_url(&parameters, &response),
+            Err(Error::MissingParameter(parameter)) if parameter == "client_id"
+        ));
+    }
+
+    #[test]
+    fn pushed_authorization_request_generates_state_and_nonce() {
+        let base_url = Url::parse("https://YOUR_DOMAIN").unwrap();
+        let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+        let pushed_authorization_request = Api::init(base_url, authentication);
+        let parameters = pushed_authorization_request::RequestParameters {
+            client_id: Some(String::from("some_awesome_client_id")),
+            client_secret: None,
+            response_type: ResponseType::Code,
+            redirect_uri: None,
+            audience: None,
+            scope: Some(String::from("openid profile")),
+            state: None,
+            nonce: None,
+            connection: None,
+            organization: None,
+            prompt: None,
+            code_challenge_method: None,
+            code_challenge: None,
+        };
+        let pushed = pushed_authorization_request.pushed_authorization_request(parameters);
+        let request = pushed.request.build().unwrap();
+        let parameters = pushed.parameters;
+        let state = parameters.state.clone().unwrap();
+        let nonce = parameters.nonce.clone().unwrap();
+        assert_eq!(state.len(), 32);
+        assert_eq!(nonce.len(), 32);
+        let test_body = format!(
+            "client_id=some_awesome_client_id&\
+            response_type=code&\
+            scope=openid+profile&\
+            state={}&\
+            nonce={}",
+            state, nonce
+        );
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+
+        let response = pushed_authorization_request::Response {
+            request_uri: String::from("urn:ietf:params:oauth:request_uri:some_awesome_id"),
+            expires_in: 30,
+        };
+        let authorize_url = pushed_authorization_request
+            .pushed_authorize_url(&parameters, &response)
+            .unwrap();
+        assert_eq!(authorize_url.state, state);
+        assert_eq!(authorize_url.nonce, Some(nonce));
+        let callback =
+            Url::parse("https://some_awesome_redirect_uri/callback?code=some_awesome_code")
+                .unwrap();
+        assert!(matches!(
+            authorize_url.validate_callback(&callback),
+            Err(crate::error::CallbackError::StateMismatch)
+        ));
+    }
+}
@@ -1,15 +1,22 @@
-use crate::authentication::Api;
+use crate::authentication::{Api, ClientCredentials};
 use reqwest::RequestBuilder;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
 pub struct RequestParameters {
-    pub client_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_secret: Option<String>,
     pub token: String,
 }
 
+impl ClientCredentials for RequestParameters {
+    fn client_credentials(&self) -> (Option<&str>, Option<&str>) {
+        (self.client_id.as_deref(), self.client_secret.as_deref())
+    }
+}
+
 pub trait RevokeRequestToken {
     fn revoke_refresh_token(&self, parameters: RequestParameters) -> RequestBuilder;
 }
@@ -19,7 +26,8 @@ impl RevokeRequestToken for Api {
         let endpoint = String::from("/oauth/revoke");
         let url = self.base_url.join(&endpoint).unwrap();
 
-        self.client.post(url).json(&request)
+        self.client_authentication(&request)
+            .json(self.client.post(url))
     }
 }
 
@@ -34,7 +42,7 @@ mod tests {
         let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
         let revoke_refresh_token = Api::init(base_url, authentication);
         let parameters = revoke_refresh_token::RequestParameters {
-            client_id: String::from("some_awesome_client_id"),
+            client_id: Some(String::from("some_awesome_client_id")),
             client_secret: Some(String::from("some_awesome_client_secret")),
             token: String::from("some_awesome_token"),
         };
@@ -38,6 +38,7 @@ impl SAML for Api {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
     use crate::authentication::*;
@@ -1,7 +1,16 @@
-use crate::authentication::Api;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::authentication::{id_token, Api};
+use crate::error::Error;
+use crate::models;
 use reqwest::header::{HeaderMap, HeaderValue};
 use reqwest::RequestBuilder;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::Mutex;
 
 #[derive(Serialize, Deserialize)]
 pub struct RequestParameters {
@@ -26,7 +35,99 @@ impl UserInfo for Api {
     }
 }
 
+impl Api {
+    /// Fetches the user's claims, from the [`UserInfoCache`] if one is configured.
+    pub async fn get_user_info(&self, access_token: &str) -> Result<models::UserInfo, Error> {
+        self.user_info_as(access_token).await
+    }
+
+    /// Fetches the user's claims into a custom type, from the [`UserInfoCache`] if one is
+    /// configured.
+    pub async fn user_info_as<T: DeserializeOwned>(&self, access_token: &str) -> Result<T, Error> {
+        let cache = self.user_info_cache.as_ref();
+        let cached = match cache {
+            Some(cache) => cache.get(access_token).await,
+            None => None,
+        };
+        let user_info = match cached {
+            Some(user_info) => user_info,
+            None => {
+                let request = self.user_info(RequestParameters {
+                    access_token: access_token.to_string(),
+                });
+                let user_info = self
+                    .send(request)
+                    .await?
+                    .json::<serde_json::Value>()
+                    .await?;
+                if let Some(cache) = cache {
+                    cache.insert(access_token, user_info.clone()).await;
+                }
+                user_info
+            }
+        };
+        Ok(serde_json::from_value(user_info)?)
+    }
+}
+
+/// Expiry and cached response of an entry.
+type CacheEntry = (Instant, serde_json::Value);
+
+/// Caches `/userinfo` responses per access token, until the token expires or `max_ttl` elapses,
+/// whichever comes first. Opaque tokens, whose expiry is unknown, are kept for `max_ttl`.
+#[derive(Debug, Clone)]
+pub struct UserInfoCache {
+    max_ttl: Duration,
+    entries: Arc<Mutex<HashMap<Vec<u8>, CacheEntry>>>,
+}
+
+impl UserInfoCache {
+    pub fn new(max_ttl: Duration) -> Self {
+        UserInfoCache {
+            max_ttl,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    async fn get(&self, access_token: &str) -> Option<serde_json::Value> {
+        let entries = self.entries.lock().await;
+        match entries.get(&Self::key(access_token)) {
+            Some((expires_at, user_info)) if *expires_at > Instant::now() => {
+                Some(user_info.clone())
+            }
+            _ => None,
+        }
+    }
+
+    async fn insert(&self, access_token: &str, user_info: serde_json::Value) {
+        let ttl = match Self::token_ttl(access_token) {
+            Some(ttl) => ttl.min(self.max_ttl),
+            None => self.max_ttl,
+        };
+        if ttl.is_zero() {
+            return;
+        }
+        let now = Instant::now();
+        let mut entries = self.entries.lock().await;
+        entries.retain(|_, (expires_at, _)| *expires_at > now);
+        entries.insert(Self::key(access_token), (now + ttl, user_info));
+    }
+
+    /// Keys entries by a hash, so the cache doesn't hold on to access tokens.
+    fn key(access_token: &str) -> Vec<u8> {
+        Sha256::digest(access_token.as_bytes()).to_vec()
+    }
+
+    /// Time left until a JWT access token expires.
+    fn token_ttl(access_token: &str) -> Option<Duration> {
+        let exp = id_token::decode(access_token).ok()?.1.exp?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+        Some(Duration::from_secs(exp.saturating_sub(now)))
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
     use crate::authentication::*;
@@ -54,4 +155,37 @@ mod tests {
         );
         assert_eq!(request.body().is_none(), true);
     }
+
+    fn some_awesome_access_token(exp: u64) -> String {
+        use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+        use base64::Engine;
+        let claims = serde_json::json!({
+            "iss": "https://YOUR_DOMAIN/",
+            "aud": "some_awesome_audience",
+            "exp": exp,
+        });
+        format!(
+            "{}.{}.c2lnbmF0dXJl",
+            URL_SAFE_NO_PAD.encode(r#"{"alg":"RS256"}"#),
+            URL_SAFE_NO_PAD.encode(claims.to_string()),
+        )
+    }
+
+    #[tokio::test]
+    async fn user_info_cache_expires_with_token() {
+        let cache = UserInfoCache::new(Duration::from_secs(300));
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let user_info = serde_json::json!({"sub": "some_awesome_user_id"});
+        let expired = some_awesome_access_token(now - 10);
+        cache.insert(&expired, user_info.clone()).await;
+        assert!(cache.get(&expired).await.is_none());
+        let valid = some_awesome_access_token(now + 60);
+        cache.insert(&valid, user_info.clone()).await;
+        assert_eq!(cache.get(&valid).await, Some(user_info));
+        let ttl = UserInfoCache::token_ttl(&valid).unwrap();
+        assert!((59..=60).contains(&ttl.as_secs()));
+    }
 }
@@ -1,23 +1,145 @@
 use std::collections::HashMap;
+use std::fmt;
 
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AuthenticationErrorResponse {
+    #[serde(default)]
     pub error_description: String,
+    /// HTTP status of the response the error was decoded from, when known.
+    #[serde(skip)]
+    pub status: Option<u16>,
+    /// Any other properties of the error body.
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ManagementErrorResponse {
+    /// HTTP status of the response; [`from_body`](ManagementErrorResponse::from_body) prefers it
+    /// over the `statusCode` of the body.
+    #[serde(default)]
     pub status_code: u16,
     pub error: String,
     pub message: String,
-    pub attributes: Option<HashMap<String, String>>,
+    pub error_code: Option<ManagementErrorCode>,
+    pub attributes: Option<HashMap<String, serde_json::Value>>,
+}
+
+impl ManagementErrorResponse {
+    /// Decodes the body of a non-2xx Management API response. Bodies that aren't a Management
+    /// error (e.g. a proxy error page) are reported as an [`UNKNOWN_ERROR`] with the body, or the
+    /// status' reason phrase if it is empty, as the message.
+    pub fn from_body(status: StatusCode, body: &str) -> Self {
+        match serde_json::from_str::<ManagementErrorResponse>(body) {
+            Ok(response) => ManagementErrorResponse {
+                status_code: status.as_u16(),
+                ..response
+            },
+            Err(_) => ManagementErrorResponse {
+                status_code: status.as_u16(),
+                error: String::from(UNKNOWN_ERROR),
+                message: unknown_error_description(status, body),
+                error_code: None,
+                attributes: None,
+            },
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        StatusCode::from_u16(self.status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+/// Machine readable `errorCode` of a Management API error. Codes without a variant are kept in
+/// `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum ManagementErrorCode {
+    InexistentUser,
+    InexistentClient,
+    InexistentConnection,
+    InexistentRole,
+    InexistentOrganization,
+    InexistentResourceServer,
+    InsufficientScope,
+    InvalidBody,
+    InvalidQueryString,
+    InvalidUri,
+    InvalidPaging,
+    InvalidToken,
+    InvalidSignature,
+    TokenExpired,
+    OperationNotSupported,
+    TooManyRequests,
+    Other(String),
+}
+
+impl ManagementErrorCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ManagementErrorCode::InexistentUser => "inexistent_user",
+            ManagementErrorCode::InexistentClient => "inexistent_client",
+            ManagementErrorCode::InexistentConnection => "inexistent_connection",
+            ManagementErrorCode::InexistentRole => "inexistent_role",
+            ManagementErrorCode::InexistentOrganization => "inexistent_organization",
+            ManagementErrorCode::InexistentResourceServer => "inexistent_resource_server",
+            ManagementErrorCode::InsufficientScope => "insufficient_scope",
+            ManagementErrorCode::InvalidBody => "invalid_body",
+            ManagementErrorCode::InvalidQueryString => "invalid_query_string",
+            ManagementErrorCode::InvalidUri => "invalid_uri",
+            ManagementErrorCode::InvalidPaging => "invalid_paging",
+            ManagementErrorCode::InvalidToken => "invalid_token",
+            ManagementErrorCode::InvalidSignature => "invalid_signature",
+            ManagementErrorCode::TokenExpired => "token_expired",
+            ManagementErrorCode::OperationNotSupported => "operation_not_supported",
+            ManagementErrorCode::TooManyRequests => "too_many_requests",
+            ManagementErrorCode::Other(code) => code,
+        }
+    }
+}
+
+impl From<String> for ManagementErrorCode {
+    fn from(code: String) -> Self {
+        match code.as_str() {
+            "inexistent_user" => ManagementErrorCode::InexistentUser,
+            "inexistent_client" => ManagementErrorCode::InexistentClient,
+            "inexistent_connection" => ManagementErrorCode::InexistentConnection,
+            "inexistent_role" => ManagementErrorCode::InexistentRole,
+            "inexistent_organization" => ManagementErrorCode::InexistentOrganization,
+            "inexistent_resource_server" => ManagementErrorCode::InexistentResourceServer,
+            "insufficient_scope" => ManagementErrorCode::InsufficientScope,
+            "invalid_body" => ManagementErrorCode::InvalidBody,
+            "invalid_query_string" => ManagementErrorCode::InvalidQueryString,
+            "invalid_uri" => ManagementErrorCode::InvalidUri,
+            "invalid_paging" => ManagementErrorCode::InvalidPaging,
+            "invalid_token" => ManagementErrorCode::InvalidToken,
+            "invalid_signature" => ManagementErrorCode::InvalidSignature,
+            "token_expired" => ManagementErrorCode::TokenExpired,
+            "operation_not_supported" => ManagementErrorCode::OperationNotSupported,
+            "too_many_requests" => ManagementErrorCode::TooManyRequests,
+            _ => ManagementErrorCode::Other(code),
+        }
+    }
+}
+
+impl From<ManagementErrorCode> for String {
+    fn from(code: ManagementErrorCode) -> Self {
+        code.as_str().to_string()
+    }
+}
+
+impl fmt::Display for ManagementErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 #[derive(Debug, Clone, thiserror::Error, Deserialize, Serialize)]
-#[serde(tag = "error", rename_all = "snake_case")]
+#[serde(from = "RawAuthenticationError", into = "RawAuthenticationError")]
 pub enum AuthenticationError {
     #[error("Bad request: {0:?}")]
     InvalidRequest(AuthenticationErrorResponse),
@@ -40,6 +162,36 @@ pub enum AuthenticationError {
     #[error("Forbidden: {0:?}")]
     InvalidGrant(AuthenticationErrorResponse),
 
+    #[error("Forbidden: {0:?}")]
+    InvalidUserPassword(AuthenticationErrorResponse),
+
+    #[error("Forbidden: {0:?}")]
+    PasswordLeaked(AuthenticationErrorResponse),
+
+    #[error("Forbidden: {response:?}")]
+    MfaRequired {
+        mfa_token: String,
+        response: AuthenticationErrorResponse,
+    },
+
+    #[error("Authorization pending: {0:?}")]
+    AuthorizationPending(AuthenticationErrorResponse),
+
+    #[error("Slow down: {0:?}")]
+    SlowDown(AuthenticationErrorResponse),
+
+    #[error("Expired token: {0:?}")]
+    ExpiredToken(AuthenticationErrorResponse),
+
+    #[error("Login required: {0:?}")]
+    LoginRequired(AuthenticationErrorResponse),
+
+    #[error("Consent required: {0:?}")]
+    ConsentRequired(AuthenticationErrorResponse),
+
+    #[error("Interaction required: {0:?}")]
+    InteractionRequired(AuthenticationErrorResponse),
+
     #[error("Not found: {0:?}")]
     EndpointDisabled(AuthenticationErrorResponse),
 
@@ -57,6 +209,262 @@ pub enum AuthenticationError {
 
     #[error("Service unavailable: {0:?}")]
     TemporarilyUnavailable(AuthenticationErrorResponse),
+
+    /// Any error code without a dedicated variant.
+    #[error("{0}: {1:?}")]
+    Other(String, AuthenticationErrorResponse),
+}
+
+impl AuthenticationError {
+    /// Decodes the body of a non-2xx Authentication API response, attaching the HTTP status.
+    /// Bodies that aren't an OAuth error are reported as an `Other` error with the code
+    /// [`UNKNOWN_ERROR`] and the body, or the status' reason phrase if it is empty, as the
+    /// description.
+    pub fn from_body(status: StatusCode, body: &str) -> Self {
+        let mut error = serde_json::from_str::<AuthenticationError>(body).unwrap_or_else(|_| {
+            AuthenticationError::Other(
+                String::from(UNKNOWN_ERROR),
+                AuthenticationErrorResponse {
+                    error_description: unknown_error_description(status, body),
+                    ..Default::default()
+                },
+            )
+        });
+        error.response_mut().status = Some(status.as_u16());
+        error
+    }
+
+    /// The `error` code as returned by Auth0.
+    pub fn code(&self) -> &str {
+        match self {
+            AuthenticationError::InvalidRequest(_) => "invalid_request",
+            AuthenticationError::InvalidScope(_) => "invalid_scope",
+            AuthenticationError::InvalidClient(_) => "invalid_client",
+            AuthenticationError::RequestValidation(_) => "request_validation",
+            AuthenticationError::UnauthorizedClient(_) => "unauthorized_client",
+            AuthenticationError::AccessDenied(_) => "access_denied",
+            AuthenticationError::InvalidGrant(_) => "invalid_grant",
+            AuthenticationError::InvalidUserPassword(_) => "invalid_user_password",
+            AuthenticationError::PasswordLeaked(_) => "password_leaked",
+            AuthenticationError::MfaRequired { .. } => "mfa_required",
+            AuthenticationError::AuthorizationPending(_) => "authorization_pending",
+            AuthenticationError::SlowDown(_) => "slow_down",
+            AuthenticationError::ExpiredToken(_) => "expired_token",
+            AuthenticationError::LoginRequired(_) => "login_required",
+            AuthenticationError::ConsentRequired(_) => "consent_required",
+            AuthenticationError::InteractionRequired(_) => "interaction_required",
+            AuthenticationError::EndpointDisabled(_) => "endpoint_disabled",
+            AuthenticationError::MethodNotAllowed(_) => "method_not_allowed",
+            AuthenticationError::TooManyRequests(_) => "too_many_requests",
+            AuthenticationError::UnsupportedResponseType(_) => "unsupported_response_type",
+            AuthenticationError::UnsupportedGrantType(_) => "unsupported_grant_type",
+            AuthenticationError::TemporarilyUnavailable(_) => "temporarily_unavailable",
+            AuthenticationError::Other(code, _) => code,
+        }
+    }
+
+    pub fn response(&self) -> &AuthenticationErrorResponse {
+        match self {
+            AuthenticationError::MfaRequired { response, .. } => response,
+            AuthenticationError::Other(_, response) => response,
+            AuthenticationError::InvalidRequest(response)
+            | AuthenticationError::InvalidScope(response)
+            | AuthenticationError::InvalidClient(response)
+            | AuthenticationError::RequestValidation(response)
+            | AuthenticationError::UnauthorizedClient(response)
+            | AuthenticationError::AccessDenied(response)
+            | AuthenticationError::InvalidGrant(response)
+            | AuthenticationError::InvalidUserPassword(response)
+            | AuthenticationError::PasswordLeaked(response)
+            | AuthenticationError::AuthorizationPending(response)
+            | AuthenticationError::SlowDown(response)
+            | AuthenticationError::ExpiredToken(response)
+            | AuthenticationError::LoginRequired(response)
+            | AuthenticationError::ConsentRequired(response)
+            | AuthenticationError::InteractionRequired(response)
+            | AuthenticationError::EndpointDisabled(response)
+            | AuthenticationError::MethodNotAllowed(response)
+            | AuthenticationError::TooManyRequests(response)
+            | AuthenticationError::UnsupportedResponseType(response)
+            | AuthenticationError::UnsupportedGrantType(response)
+            | AuthenticationError::TemporarilyUnavailable(response) => response,
+        }
+    }
+
+    fn response_mut(&mut self) -> &mut AuthenticationErrorResponse {
+        match self {
+            AuthenticationError::MfaRequired { response, .. } => response,
+            AuthenticationError::Other(_, response) => response,
+            AuthenticationError::InvalidRequest(response)
+            | AuthenticationError::InvalidScope(response)
+            | AuthenticationError::InvalidClient(response)
+            | AuthenticationError::RequestValidation(response)
+            | AuthenticationError::UnauthorizedClient(response)
+            | AuthenticationError::AccessDenied(response)
+            | AuthenticationError::InvalidGrant(response)
+            | AuthenticationError::InvalidUserPassword(response)
+            | AuthenticationError::PasswordLeaked(response)
+            | AuthenticationError::AuthorizationPending(response)
+            | AuthenticationError::SlowDown(response)
+            | AuthenticationError::ExpiredToken(response)
+            | AuthenticationError::LoginRequired(response)
+            | AuthenticationError::ConsentRequired(response)
+            | AuthenticationError::InteractionRequired(response)
+            | AuthenticationError::EndpointDisabled(response)
+            | AuthenticationError::MethodNotAllowed(response)
+            | AuthenticationError::TooManyRequests(response)
+            | AuthenticationError::UnsupportedResponseType(response)
+            | AuthenticationError::UnsupportedGrantType(response)
+            | AuthenticationError::TemporarilyUnavailable(response) => response,
+        }
+    }
+
+    pub fn status(&self) -> Option<StatusCode> {
+        self.response()
+            .status
+            .and_then(|status| StatusCode::from_u16(status).ok())
+    }
+}
+
+/// Wire format of [`AuthenticationError`]: the `error` code next to the rest of the body.
+#[derive(Clone, Serialize, Deserialize)]
+struct RawAuthenticationError {
+    error: String,
+    #[serde(flatten)]
+    response: AuthenticationErrorResponse,
+}
+
+impl From<RawAuthenticationError> for AuthenticationError {
+    fn from(raw: RawAuthenticationError) -> Self {
+        let RawAuthenticationError {
+            error,
+            mut response,
+        } = raw;
+        match error.as_str() {
+            "invalid_request" => AuthenticationError::InvalidRequest(response),
+            "invalid_scope" => AuthenticationError::InvalidScope(response),
+            "invalid_client" => AuthenticationError::InvalidClient(response),
+            "request_validation" => AuthenticationError::RequestValidation(response),
+            "unauthorized_client" => AuthenticationError::UnauthorizedClient(response),
+            "access_denied" => AuthenticationError::AccessDenied(response),
+            "invalid_grant" => AuthenticationError::InvalidGrant(response),
+            "invalid_user_password" => AuthenticationError::InvalidUserPassword(response),
+            "password_leaked" => AuthenticationError::PasswordLeaked(response),
+            "mfa_required" => match response.other.remove("mfa_token") {
+                Some(serde_json::Value::String(mfa_token)) => AuthenticationError::MfaRequired {
+                    mfa_token,
+                    response,
+                },
+                mfa_token => {
+                    if let Some(mfa_token) = mfa_token {
+                        response.other.insert(String::from("mfa_token"), mfa_token);
+                    }
+                    AuthenticationError::Other(error, response)
+                }
+            },
+            "authorization_pending" => AuthenticationError::AuthorizationPending(response),
+            "slow_down" => AuthenticationError::SlowDown(response),
+            "expired_token" => AuthenticationError::ExpiredToken(response),
+            "login_required" => AuthenticationError::LoginRequired(response),
+            "consent_required" => AuthenticationError::ConsentRequired(response),
+            "interaction_required" => AuthenticationError::InteractionRequired(response),
+            "endpoint_disabled" => AuthenticationError::EndpointDisabled(response),
+            "method_not_allowed" => AuthenticationError::MethodNotAllowed(response),
+            "too_many_requests" => AuthenticationError::TooManyRequests(response),
+            "unsupported_response_type" => AuthenticationError::UnsupportedResponseType(response),
+            "unsupported_grant_type" => AuthenticationError::UnsupportedGrantType(response),
+            "temporarily_unavailable" => AuthenticationError::TemporarilyUnavailable(response),
+            _ => AuthenticationError::Other(error, response),
+        }
+    }
+}
+
+impl From<AuthenticationError> for RawAuthenticationError {
+    fn from(error: AuthenticationError) -> Self {
+        let code = error.code().to_string();
+        let response = match error {
+            AuthenticationError::MfaRequired {
+                mfa_token,
+                mut response,
+            } => {
+                response
+                    .other
+                    .insert(String::from("mfa_token"), mfa_token.into());
+                response
+            }
+            error => error.response().clone(),
+        };
+        RawAuthenticationError {
+            error: code,
+            response,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum IdTokenError {
+    #[error("Malformed token")]
+    Malformed,
+
+    #[error("Unsupported algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+
+    #[error("Missing claim: {0}")]
+    MissingClaim(String),
+
+    #[error("Nonce mismatch")]
+    NonceMismatch,
+
+    #[error("Hash mismatch: {0}")]
+    HashMismatch(String),
+
+    #[error("Unknown signing key: {0:?}")]
+    UnknownKey(Option<String>),
+
+    #[error("Invalid token: {0}")]
+    Invalid(#[from] jsonwebtoken::errors::Error),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LogoutTokenError {
+    #[error("Missing parameter: logout_token")]
+    MissingToken,
+
+    #[error("Unknown signing key: {0:?}")]
+    UnknownKey(Option<String>),
+
+    #[error("Invalid token type: {0}")]
+    InvalidType(String),
+
+    #[error("Invalid token: {0}")]
+    Invalid(#[from] jsonwebtoken::errors::Error),
+
+    #[error("Missing back-channel logout event")]
+    MissingEvent,
+
+    #[error("Missing claim: sid or sub")]
+    MissingSubject,
+
+    #[error("Nonce not allowed")]
+    NonceNotAllowed,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CallbackError {
+    #[error("State mismatch")]
+    StateMismatch,
+
+    #[error("Missing parameter: {0}")]
+    MissingParameter(String),
+
+    #[error("Invalid parameters: {0}")]
+    InvalidParameters(String),
+
+    #[error("Authentication error: {0:?}")]
+    AuthenticationError(AuthenticationError),
+
+    #[error("Invalid ID token: {0}")]
+    IdTokenError(#[from] IdTokenError),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -69,4 +477,114 @@ pub enum Error {
 
     #[error("Management error: {0:?}")]
     ManagementError(ManagementErrorResponse),
+
+    #[error("Callback error: {0:?}")]
+    CallbackError(#[from] CallbackError),
+
+    #[error("Client assertion error: {0:?}")]
+    ClientAssertionError(#[from] jsonwebtoken::errors::Error),
+
+    #[error("Logout token error: {0:?}")]
+    LogoutTokenError(#[from] LogoutTokenError),
+
+    #[error("Deserialization error: {0:?}")]
+    DeserializationError(#[from] serde_json::Error),
+
+    #[error("Missing parameter: {0}")]
+    MissingParameter(String),
+}
+
+/// Error code of a non-2xx response whose body isn't an Auth0 error, e.g. a proxy error page.
+pub const UNKNOWN_ERROR: &str = "unknown";
+
+fn unknown_error_description(status: StatusCode, body: &str) -> String {
+    match body.trim() {
+        "" => status.canonical_reason().unwrap_or_default().to_string(),
+        _ => body.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn authentication_error_from_body() {
+        let body = r#"{"error":"mfa_required",
+            "error_description":"Multifactor authentication required",
+            "mfa_token":"some_awesome_mfa_token"}"#;
+        match AuthenticationError::from_body(StatusCode::FORBIDDEN, body) {
+            AuthenticationError::MfaRequired {
+                mfa_token,
+                response,
+            } => {
+                assert_eq!(mfa_token, "some_awesome_mfa_token");
+                assert_eq!(response.status, Some(403));
+            }
+            error => panic!("unexpected error: {:?}", error),
+        }
+
+        let body = r#"{"error":"some_awesome_error","some_awesome_field":1}"#;
+        let error = AuthenticationError::from_body(StatusCode::BAD_REQUEST, body);
+        assert_eq!(error.code(), "some_awesome_error");
+        assert_eq!(error.status(), Some(StatusCode::BAD_REQUEST));
+        assert_eq!(error.response().other["some_awesome_field"], 1);
+        assert_eq!(
+            serde_json::to_string(&error).unwrap(),
+            "{\"error\":\"some_awesome_error\",\"error_description\":\"\",\"some_awesome_field\":1}",
+        );
+
+        let error = AuthenticationError::from_body(StatusCode::BAD_GATEWAY, "<html></html>");
+        assert_eq!(error.code(), UNKNOWN_ERROR);
+        assert_eq!(error.status(), Some(StatusCode::BAD_GATEWAY));
+        assert_eq!(error.response().error_description, "<html></html>");
+
+        let error = AuthenticationError::from_body(StatusCode::SERVICE_UNAVAILABLE, "");
+        assert_eq!(error.code(), UNKNOWN_ERROR);
+        assert_eq!(error.response().error_description, "Service Unavailable");
+    }
+
+    #[test]
+    fn management_error_response_from_body() {
+        let body = r#"{"statusCode":404,
+            "error":"Not Found",
+            "message":"The user does not exist.",
+            "errorCode":"inexistent_user"}"#;
+        let error = ManagementErrorResponse::from_body(StatusCode::NOT_FOUND, body);
+        assert_eq!(error.status(), StatusCode::NOT_FOUND);
+        assert_eq!(error.error_code, Some(ManagementErrorCode::InexistentUser));
+
+        let body = r#"{"statusCode":409,
+            "error":"Conflict",
+            "message":"Some awesome conflict",
+            "errorCode":"some_awesome_code"}"#;
+        let error = ManagementErrorResponse::from_body(StatusCode::CONFLICT, body);
+        assert_eq!(
+            error.error_code,
+            Some(ManagementErrorCode::Other(String::from(
+                "some_awesome_code"
+            )))
+        );
+
+        let body = r#"{"statusCode":400,
+            "error":"Bad Request",
+            "message":"Payload validation error",
+            "errorCode":"invalid_body",
+            "attributes":{"limit":10,"strict":true,"path":{"segment":"some_awesome_segment"}}}"#;
+        let error = ManagementErrorResponse::from_body(StatusCode::UNPROCESSABLE_ENTITY, body);
+        assert_eq!(error.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(error.error_code, Some(ManagementErrorCode::InvalidBody));
+        let attributes = error.attributes.unwrap();
+        assert_eq!(attributes["limit"], 10);
+        assert_eq!(attributes["path"]["segment"], "some_awesome_segment");
+
+        let error = ManagementErrorResponse::from_body(StatusCode::BAD_GATEWAY, "<html></html>");
+        assert_eq!(error.status(), StatusCode::BAD_GATEWAY);
+        assert_eq!(error.error, UNKNOWN_ERROR);
+        assert_eq!(error.message, "<html></html>");
+
+        let error = ManagementErrorResponse::from_body(StatusCode::SERVICE_UNAVAILABLE, "");
+        assert_eq!(error.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(error.message, "Service Unavailable");
+    }
 }
@@ -2,3 +2,4 @@ pub mod authentication;
 pub mod error;
 pub mod management;
 pub mod models;
+pub mod retry;
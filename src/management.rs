@@ -3,21 +3,40 @@ use std::sync::Arc;
 use crate::{
     authentication::{
         self,
+        client_assertion::PrivateKeyJwt,
         get_token::{client_credentials_flow, GetToken},
     },
-    error::Error,
-    models::AuthenticationResponse,
+    error::{Error, ManagementErrorResponse},
+    retry::{self, RateLimiter, RetryPolicy},
 };
-use reqwest::{header, Client, RequestBuilder, Url};
+use reqwest::{header, Client, RequestBuilder, Response, Url};
 use tokio::sync::Mutex;
 
+pub mod attack_protection;
+pub mod blacklists;
+pub mod branding;
+pub mod connections;
+pub mod custom_domains;
+pub mod device_credentials;
+pub mod email_templates;
+pub mod emails;
+pub mod flows;
+pub mod forms;
+pub mod grants;
+pub mod keys;
+pub mod prompts;
 pub mod roles;
+pub mod self_service_profiles;
+pub mod stats;
+pub mod tenants;
 pub mod users;
 
 pub struct Api {
     pub base_url: Url,
     access_token: Arc<Mutex<client_credentials_flow::Response>>,
     client: Client,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
 }
 
 impl Api {
@@ -25,35 +44,45 @@ impl Api {
         base_url: Url,
         client_id: String,
         client_secret: String,
+    ) -> Result<Self, Error> {
+        let authentication =
+            authentication::AuthenticationMethod::ClientIDClientSecret(client_id, client_secret);
+        Self::init_with_authentication(base_url, authentication).await
+    }
+
+    /// Like [`Api::init`], but fetches access tokens with a signed `client_assertion` instead
+    /// of a client secret.
+    pub async fn init_with_private_key_jwt(
+        base_url: Url,
+        private_key_jwt: PrivateKeyJwt,
+    ) -> Result<Self, Error> {
+        let authentication = authentication::AuthenticationMethod::PrivateKeyJwt(private_key_jwt);
+        Self::init_with_authentication(base_url, authentication).await
+    }
+
+    async fn init_with_authentication(
+        base_url: Url,
+        authentication: authentication::AuthenticationMethod,
     ) -> Result<Self, Error> {
         let client = Self::build_client();
 
-        // Instantiate a Authentication API client for getting client_credentials token
-        let auth = authentication::Api::init(
-            base_url.clone(),
-            authentication::AuthenticationMethod::ClientIDClientSecret(
-                client_id.clone(),
-                client_secret.clone(),
-            ),
+        // Instantiate a Authentication API client for getting client_credentials token; it adds
+        // the client credentials to each token request. A throttled token request has no side
+        // effects, so it is retried even though it is a POST.
+        let auth = authentication::Api::init(base_url.clone(), authentication).with_retry_policy(
+            RetryPolicy {
+                retry_throttled_non_idempotent: true,
+                ..Default::default()
+            },
         );
 
         tracing::info!("Fetching access token (client_credentials) from Auth0");
         let params = authentication::get_token::client_credentials_flow::RequestParameters {
-            grant_type: "client_credentials".to_string(),
-            client_id: client_id.clone(),
-            client_secret: client_secret.clone(),
+            client_id: None,
+            client_secret: None,
             audience: base_url.join("/api/v2/").unwrap().to_string(),
         };
-        let access_token = match auth
-            .client_credentials_flow(params.clone())
-            .send()
-            .await?
-            .json::<AuthenticationResponse<client_credentials_flow::Response>>()
-            .await?
-        {
-            AuthenticationResponse::Success(r) => r,
-            AuthenticationResponse::Error(e) => return Err(e.into()),
-        };
+        let access_token = Self::fetch_access_token(&auth, params.clone()).await?;
         let access_token = Arc::new(Mutex::new(access_token));
         tracing::info!("Fetched access token (client_credentials) from Auth0");
 
@@ -63,19 +92,20 @@ impl Api {
             let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
             interval.tick().await;
 
-            // Fetch a new token every hour
+            // Fetch a new token every hour; on failure the current token is kept until the next
+            // attempt
             loop {
                 interval.tick().await;
-                let mut token = token.lock().await;
-                *token = auth
-                    .client_credentials_flow(params.clone())
-                    .send()
-                    .await
-                    .unwrap()
-                    .json::<client_credentials_flow::Response>()
-                    .await
-                    .unwrap();
-                tracing::info!("Refreshed access token (client_credentials) from Auth0");
+                match Self::fetch_access_token(&auth, params.clone()).await {
+                    Ok(access_token) => {
+                        *token.lock().await = access_token;
+                        tracing::info!("Refreshed access token (client_credentials) from Auth0");
+                    }
+                    Err(error) => tracing::error!(
+                        "Failed to refresh access token (client_credentials) from Auth0: {}",
+                        error
+                    ),
+                }
             }
         });
 
@@ -83,9 +113,54 @@ impl Api {
             base_url,
             client,
             access_token,
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: None,
         })
     }
 
+    async fn fetch_access_token(
+        auth: &authentication::Api,
+        params: client_credentials_flow::RequestParameters,
+    ) -> Result<client_credentials_flow::Response, Error> {
+        Ok(auth
+            .send(auth.client_credentials_flow(params))
+            .await?
+            .json::<client_credentials_flow::Response>()
+            .await?)
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Limits outgoing requests client-side, e.g. to the tenant's Management API quota.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Sends a request built by this client, applying its retry policy and rate limiter.
+    ///
+    /// Non-2xx responses are decoded into [`Error::ManagementError`].
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response, Error> {
+        let response = retry::send(
+            &self.client,
+            request,
+            &self.retry_policy,
+            self.rate_limiter.as_ref(),
+        )
+        .await?;
+        if response.status().is_success() {
+            return Ok(response);
+        }
+        let status = response.status();
+        let body = response.text().await?;
+        Err(Error::ManagementError(ManagementErrorResponse::from_body(
+            status, &body,
+        )))
+    }
+
     fn apply_auth(&self, rb: RequestBuilder) -> RequestBuilder {
         let token = self.access_token.clone();
         tokio::task::block_in_place(move || {
@@ -112,8 +187,29 @@ impl Api {
 }
 
 #[cfg(test)]
-mod tests {
+#[allow(clippy::bool_assert_comparison)]
+pub(crate) mod tests {
     use super::*;
+    use mockito::mock;
+
+    pub(crate) async fn init_api() -> Api {
+        let _mock = mock("POST", "/oauth/token")
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"access_token":"some_awesome_access_token",
+                "expires_in":86400,
+                "token_type":"Bearer"}"#,
+            )
+            .create();
+        let base_url = Url::parse(&mockito::server_url()).unwrap();
+        Api::init(
+            base_url,
+            String::from("some_awesome_id"),
+            String::from("some_awesome_token"),
+        )
+        .await
+        .unwrap()
+    }
 
     #[tracing_test::traced_test]
     #[tokio::test]
@@ -137,4 +233,60 @@ mod tests {
         assert_eq!(request.headers().len(), 0);
         assert_eq!(request.body().is_none(), true);
     }
+
+    #[tokio::test]
+    async fn authentication_api_init_with_mocked_token_endpoint() {
+        let api = init_api().await;
+        let request = api
+            .client
+            .request(reqwest::Method::GET, api.base_url.clone())
+            .build()
+            .unwrap();
+        let test_url = format!("{}/", mockito::server_url());
+        assert_eq!(request.url().as_str(), test_url);
+        assert!(request.body().is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn apply_auth_build_request() {
+        let api = init_api().await;
+        let request = api
+            .apply_auth(api.client.get(api.base_url.clone()))
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.headers()[header::AUTHORIZATION],
+            "Bearer some_awesome_access_token",
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn send_decodes_management_error() {
+        let api = init_api().await;
+        let _mock = mock("GET", "/api/v2/some_awesome_missing_resource")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"statusCode":404,
+                "error":"Not Found",
+                "message":"Some awesome message",
+                "errorCode":"inexistent_user"}"#,
+            )
+            .create();
+        let url = api
+            .base_url
+            .join("/api/v2/some_awesome_missing_resource")
+            .unwrap();
+        let request = api.apply_auth(api.client.get(url));
+        match api.send(request).await {
+            Err(Error::ManagementError(error)) => {
+                assert_eq!(error.status(), reqwest::StatusCode::NOT_FOUND);
+                assert_eq!(
+                    error.error_code,
+                    Some(crate::error::ManagementErrorCode::InexistentUser)
+                );
+            }
+            _ => panic!("expected a management error"),
+        }
+    }
 }
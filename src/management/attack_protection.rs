@@ -0,0 +1,305 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Shield {
+    Block,
+    UserNotification,
+    AdminNotification,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdminNotificationFrequency {
+    Immediately,
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreachedPasswordDetectionMethod {
+    Standard,
+    Enhanced,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BruteForceProtectionMode {
+    CountPerIdentifierAndIp,
+    CountPerIdentifier,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BreachedPasswordDetection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shields: Option<Vec<Shield>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_notification_frequency: Option<Vec<AdminNotificationFrequency>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<BreachedPasswordDetectionMethod>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BruteForceProtection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shields: Option<Vec<Shield>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowlist: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<BruteForceProtectionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThrottlingStage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SuspiciousIpThrottlingStages {
+    #[serde(rename = "pre-login", skip_serializing_if = "Option::is_none")]
+    pub pre_login: Option<ThrottlingStage>,
+    #[serde(
+        rename = "pre-user-registration",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pre_user_registration: Option<ThrottlingStage>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SuspiciousIpThrottling {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shields: Option<Vec<Shield>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowlist: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<SuspiciousIpThrottlingStages>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockedFor {
+    pub identifier: Option<String>,
+    pub ip: Option<String>,
+    pub connection: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserBlocks {
+    pub blocked_for: Vec<BlockedFor>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserBlocksByIdentifierRequestParameters {
+    pub identifier: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consider_brute_force_enablement: Option<bool>,
+}
+
+pub trait AttackProtection {
+    fn get_breached_password_detection(&self) -> RequestBuilder;
+
+    fn update_breached_password_detection(
+        &self,
+        request: BreachedPasswordDetection,
+    ) -> RequestBuilder;
+
+    fn get_brute_force_protection(&self) -> RequestBuilder;
+
+    fn update_brute_force_protection(&self, request: BruteForceProtection) -> RequestBuilder;
+
+    fn get_suspicious_ip_throttling(&self) -> RequestBuilder;
+
+    fn update_suspicious_ip_throttling(&self, request: SuspiciousIpThrottling) -> RequestBuilder;
+
+    fn check_ip_block(&self, ip: String) -> RequestBuilder;
+
+    fn unblock_ip(&self, ip: String) -> RequestBuilder;
+
+    fn get_user_blocks_by_identifier(
+        &self,
+        request: UserBlocksByIdentifierRequestParameters,
+    ) -> RequestBuilder;
+
+    fn unblock_user_by_identifier(
+        &self,
+        request: UserBlocksByIdentifierRequestParameters,
+    ) -> RequestBuilder;
+
+    fn get_user_blocks(&self, id: String) -> RequestBuilder;
+
+    fn unblock_user(&self, id: String) -> RequestBuilder;
+}
+
+impl AttackProtection for Api {
+    fn get_breached_password_detection(&self) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/attack-protection/breached-password-detection");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn update_breached_password_detection(
+        &self,
+        request: BreachedPasswordDetection,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/attack-protection/breached-password-detection");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+
+    fn get_brute_force_protection(&self) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/attack-protection/brute-force-protection");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn update_brute_force_protection(&self, request: BruteForceProtection) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/attack-protection/brute-force-protection");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+
+    fn get_suspicious_ip_throttling(&self) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/attack-protection/suspicious-ip-throttling");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn update_suspicious_ip_throttling(&self, request: SuspiciousIpThrottling) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/attack-protection/suspicious-ip-throttling");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+
+    fn check_ip_block(&self, ip: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/anomaly/blocks/ips/{}", ip);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn unblock_ip(&self, ip: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/anomaly/blocks/ips/{}", ip);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+
+    fn get_user_blocks_by_identifier(
+        &self,
+        request: UserBlocksByIdentifierRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/user-blocks");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+
+    fn unblock_user_by_identifier(
+        &self,
+        request: UserBlocksByIdentifierRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/user-blocks");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url)).query(&request)
+    }
+
+    fn get_user_blocks(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/user-blocks/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn unblock_user(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/user-blocks/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn update_suspicious_ip_throttling_build_request() {
+        let management = init_api().await;
+        let parameters = SuspiciousIpThrottling {
+            enabled: Some(true),
+            shields: Some(vec![Shield::Block, Shield::AdminNotification]),
+            allowlist: None,
+            stage: Some(SuspiciousIpThrottlingStages {
+                pre_login: Some(ThrottlingStage {
+                    max_attempts: Some(100),
+                    rate: Some(864000),
+                }),
+                pre_user_registration: None,
+            }),
+        };
+        let request = management
+            .update_suspicious_ip_throttling(parameters)
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/attack-protection/suspicious-ip-throttling",
+            mockito::server_url()
+        );
+        let test_body = String::from(
+            "{\"enabled\":true,\
+            \"shields\":[\"block\",\"admin_notification\"],\
+            \"stage\":{\"pre-login\":{\"max_attempts\":100,\"rate\":864000}}}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::PATCH);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn unblock_user_by_identifier_build_request() {
+        let management = init_api().await;
+        let parameters = UserBlocksByIdentifierRequestParameters {
+            identifier: String::from("tester@awesome.com"),
+            consider_brute_force_enablement: None,
+        };
+        let request = management
+            .unblock_user_by_identifier(parameters)
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/user-blocks?identifier=tester%40awesome.com",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::DELETE);
+        assert_eq!(request.url().as_str(), test_url);
+        assert!(request.body().is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn check_ip_block_build_request() {
+        let management = init_api().await;
+        let request = management
+            .check_ip_block(String::from("10.0.0.1"))
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/anomaly/blocks/ips/10.0.0.1",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::GET);
+        assert_eq!(request.url().as_str(), test_url);
+    }
+}
@@ -0,0 +1,75 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlacklistedToken {
+    pub aud: Option<String>,
+    pub jti: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ListBlacklistedTokensRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aud: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BlacklistTokenRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aud: Option<String>,
+    pub jti: String,
+}
+
+pub trait Blacklists {
+    fn list_blacklisted_tokens(
+        &self,
+        request: ListBlacklistedTokensRequestParameters,
+    ) -> RequestBuilder;
+
+    fn blacklist_token(&self, request: BlacklistTokenRequestParameters) -> RequestBuilder;
+}
+
+impl Blacklists for Api {
+    fn list_blacklisted_tokens(
+        &self,
+        request: ListBlacklistedTokensRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/blacklists/tokens");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+
+    fn blacklist_token(&self, request: BlacklistTokenRequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/blacklists/tokens");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url)).json(&request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn blacklist_token_build_request() {
+        let management = init_api().await;
+        let parameters = BlacklistTokenRequestParameters {
+            aud: Some(String::from("some_awesome_audience")),
+            jti: String::from("some_awesome_jti"),
+        };
+        let request = management.blacklist_token(parameters).build().unwrap();
+        let test_url = format!("{}/api/v2/blacklists/tokens", mockito::server_url());
+        let test_body = String::from(
+            "{\"aud\":\"some_awesome_audience\",\
+            \"jti\":\"some_awesome_jti\"}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BrandingColors {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_background: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrandingFont {
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrandingSettings {
+    pub colors: Option<BrandingColors>,
+    pub favicon_url: Option<String>,
+    pub logo_url: Option<String>,
+    pub font: Option<BrandingFont>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UniversalLoginTemplate {
+    pub body: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UpdateBrandingRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colors: Option<BrandingColors>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub favicon_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font: Option<BrandingFont>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SetUniversalLoginTemplateRequestParameters {
+    pub template: String,
+}
+
+pub trait Branding {
+    fn get_branding(&self) -> RequestBuilder;
+
+    fn update_branding(&self, request: UpdateBrandingRequestParameters) -> RequestBuilder;
+
+    fn get_universal_login_template(&self) -> RequestBuilder;
+
+    fn set_universal_login_template(
+        &self,
+        request: SetUniversalLoginTemplateRequestParameters,
+    ) -> RequestBuilder;
+
+    fn delete_universal_login_template(&self) -> RequestBuilder;
+}
+
+impl Branding for Api {
+    fn get_branding(&self) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/branding");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn update_branding(&self, request: UpdateBrandingRequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/branding");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+
+    fn get_universal_login_template(&self) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/branding/templates/universal-login");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn set_universal_login_template(
+        &self,
+        request: SetUniversalLoginTemplateRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/branding/templates/universal-login");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.put(url)).json(&request)
+    }
+
+    fn delete_universal_login_template(&self) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/branding/templates/universal-login");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn update_branding_build_request() {
+        let management = init_api().await;
+        let parameters = UpdateBrandingRequestParameters {
+            colors: Some(BrandingColors {
+                primary: Some(String::from("#0059d6")),
+                page_background: None,
+            }),
+            favicon_url: None,
+            logo_url: Some(String::from("some_awesome_logo_url")),
+            font: None,
+        };
+        let request = management.update_branding(parameters).build().unwrap();
+        let test_url = format!("{}/api/v2/branding", mockito::server_url());
+        let test_body = String::from(
+            "{\"colors\":{\"primary\":\"#0059d6\"},\
+            \"logo_url\":\"some_awesome_logo_url\"}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::PATCH);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn set_universal_login_template_build_request() {
+        let management = init_api().await;
+        let parameters = SetUniversalLoginTemplateRequestParameters {
+            template: String::from("{%- auth0:head -%}{%- auth0:widget -%}"),
+        };
+        let request = management
+            .set_universal_login_template(parameters)
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/branding/templates/universal-login",
+            mockito::server_url()
+        );
+        let test_body = String::from("{\"template\":\"{%- auth0:head -%}{%- auth0:widget -%}\"}");
+        assert_eq!(request.method().as_str(), reqwest::Method::PUT);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+}
@@ -0,0 +1,492 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomScripts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub get_user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verify: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete: Option<String>,
+}
+
+impl CustomScripts {
+    /// Reads `login.js`, `get_user.js`, `create.js`, `verify.js`, `change_password.js` and
+    /// `delete.js` from `dir`. Scripts that don't exist on disk are left unset.
+    pub fn from_dir<P: AsRef<Path>>(dir: P) -> io::Result<CustomScripts> {
+        let read = |name: &str| -> io::Result<Option<String>> {
+            match std::fs::read_to_string(dir.as_ref().join(format!("{}.js", name))) {
+                Ok(script) => Ok(Some(script)),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(e),
+            }
+        };
+
+        Ok(CustomScripts {
+            login: read("login")?,
+            get_user: read("get_user")?,
+            create: read("create")?,
+            verify: read("verify")?,
+            change_password: read("change_password")?,
+            delete: read("delete")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_scripts: Option<CustomScripts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_database_customization: Option<bool>,
+    /// Unlike the other options, sent in snake case.
+    #[serde(rename = "import_mode", skip_serializing_if = "Option::is_none")]
+    pub import_mode: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<HashMap<String, String>>,
+    /// Remaining strategy specific options, kept so that a fetched `options` object can be sent
+    /// back unchanged (the Management API replaces `options` as a whole on update).
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+impl ConnectionOptions {
+    /// Merges `scripts` into the current custom scripts and enables database customization, so
+    /// the scripts take effect. Scripts left unset in `scripts` keep their current value.
+    ///
+    /// The Management API replaces `options` as a whole on update: merge into options fetched
+    /// right before the update, since changes made in between are overwritten.
+    pub fn with_custom_scripts(mut self, scripts: CustomScripts) -> Self {
+        let current = self.custom_scripts.unwrap_or_default();
+        self.custom_scripts = Some(CustomScripts {
+            login: scripts.login.or(current.login),
+            get_user: scripts.get_user.or(current.get_user),
+            create: scripts.create.or(current.create),
+            verify: scripts.verify.or(current.verify),
+            change_password: scripts.change_password.or(current.change_password),
+            delete: scripts.delete.or(current.delete),
+        });
+        self.enabled_database_customization = Some(true);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Connection {
+    pub id: String,
+    pub name: String,
+    pub strategy: String,
+    pub display_name: Option<String>,
+    pub options: Option<ConnectionOptions>,
+    pub enabled_clients: Option<Vec<String>>,
+    pub realms: Option<Vec<String>>,
+    pub is_domain_connection: Option<bool>,
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct GetConnectionRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_fields: Option<bool>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct UpdateConnectionRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<ConnectionOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_clients: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub realms: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_domain_connection: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScimMapping {
+    pub auth0: String,
+    pub scim: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScimConfiguration {
+    pub connection_id: String,
+    pub connection_name: Option<String>,
+    pub strategy: Option<String>,
+    pub tenant_name: Option<String>,
+    pub user_id_attribute: Option<String>,
+    pub mapping: Option<Vec<ScimMapping>>,
+    pub created_at: Option<String>,
+    pub updated_on: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScimToken {
+    pub token_id: String,
+    /// Only returned when the token is created.
+    pub token: Option<String>,
+    pub scopes: Option<Vec<String>>,
+    pub created_at: Option<String>,
+    pub valid_until: Option<String>,
+    pub last_used_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ScimConfigurationRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id_attribute: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mapping: Option<Vec<ScimMapping>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateScimTokenRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_lifetime: Option<u64>,
+}
+
+pub trait Connections {
+    fn get_connection(&self, id: String, request: GetConnectionRequestParameters)
+        -> RequestBuilder;
+
+    fn update_connection(
+        &self,
+        id: String,
+        request: UpdateConnectionRequestParameters,
+    ) -> RequestBuilder;
+
+    /// Replaces the connection's `options`, e.g. to upload custom database scripts. See
+    /// [`ConnectionOptions::with_custom_scripts`] to merge scripts into the current options.
+    fn update_custom_scripts(&self, id: String, options: ConnectionOptions) -> RequestBuilder;
+
+    fn get_scim_configuration(&self, id: String) -> RequestBuilder;
+
+    fn get_scim_default_mapping(&self, id: String) -> RequestBuilder;
+
+    fn create_scim_configuration(
+        &self,
+        id: String,
+        request: ScimConfigurationRequestParameters,
+    ) -> RequestBuilder;
+
+    fn update_scim_configuration(
+        &self,
+        id: String,
+        request: ScimConfigurationRequestParameters,
+    ) -> RequestBuilder;
+
+    fn delete_scim_configuration(&self, id: String) -> RequestBuilder;
+
+    fn list_scim_tokens(&self, id: String) -> RequestBuilder;
+
+    fn create_scim_token(
+        &self,
+        id: String,
+        request: CreateScimTokenRequestParameters,
+    ) -> RequestBuilder;
+
+    fn delete_scim_token(&self, id: String, token_id: String) -> RequestBuilder;
+}
+
+impl Connections for Api {
+    fn get_connection(
+        &self,
+        id: String,
+        request: GetConnectionRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/connections/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+
+    fn update_connection(
+        &self,
+        id: String,
+        request: UpdateConnectionRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/connections/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+
+    fn update_custom_scripts(&self, id: String, options: ConnectionOptions) -> RequestBuilder {
+        let request = UpdateConnectionRequestParameters {
+            options: Some(options),
+            ..Default::default()
+        };
+        self.update_connection(id, request)
+    }
+
+    fn get_scim_configuration(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/connections/{}/scim-configuration", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn get_scim_default_mapping(&self, id: String) -> RequestBuilder {
+        let endpoint = format!(
+            "/api/v2/connections/{}/scim-configuration/default-mapping",
+            id
+        );
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn create_scim_configuration(
+        &self,
+        id: String,
+        request: ScimConfigurationRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/connections/{}/scim-configuration", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url)).json(&request)
+    }
+
+    fn update_scim_configuration(
+        &self,
+        id: String,
+        request: ScimConfigurationRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/connections/{}/scim-configuration", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+
+    fn delete_scim_configuration(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/connections/{}/scim-configuration", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+
+    fn list_scim_tokens(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/connections/{}/scim-configuration/tokens", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn create_scim_token(
+        &self,
+        id: String,
+        request: CreateScimTokenRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/connections/{}/scim-configuration/tokens", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url)).json(&request)
+    }
+
+    fn delete_scim_token(&self, id: String, token_id: String) -> RequestBuilder {
+        let endpoint = format!(
+            "/api/v2/connections/{}/scim-configuration/tokens/{}",
+            id, token_id
+        );
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn update_connection_build_request() {
+        let management = init_api().await;
+        let mut other = HashMap::new();
+        other.insert(
+            String::from("brute_force_protection"),
+            serde_json::json!(true),
+        );
+        let parameters = UpdateConnectionRequestParameters {
+            options: Some(ConnectionOptions {
+                custom_scripts: Some(CustomScripts {
+                    login: Some(String::from("function login() {}")),
+                    ..Default::default()
+                }),
+                enabled_database_customization: Some(true),
+                other,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let request = management
+            .update_connection(String::from("con_some_awesome_id"), parameters)
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/connections/con_some_awesome_id",
+            mockito::server_url()
+        );
+        let test_body = String::from(
+            "{\"options\":{\
+            \"customScripts\":{\"login\":\"function login() {}\"},\
+            \"enabledDatabaseCustomization\":true,\
+            \"brute_force_protection\":true}}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::PATCH);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn create_scim_configuration_build_request() {
+        let management = init_api().await;
+        let parameters = ScimConfigurationRequestParameters {
+            user_id_attribute: Some(String::from("externalId")),
+            mapping: Some(vec![ScimMapping {
+                auth0: String::from("email"),
+                scim: String::from("emails[primary eq true].value"),
+            }]),
+        };
+        let request = management
+            .create_scim_configuration(String::from("con_some_awesome_id"), parameters)
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/connections/con_some_awesome_id/scim-configuration",
+            mockito::server_url()
+        );
+        let test_body = String::from(
+            "{\"user_id_attribute\":\"externalId\",\
+            \"mapping\":[{\"auth0\":\"email\",\"scim\":\"emails[primary eq true].value\"}]}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn delete_scim_token_build_request() {
+        let management = init_api().await;
+        let request = management
+            .delete_scim_token(
+                String::from("con_some_awesome_id"),
+                String::from("tok_some_awesome_id"),
+            )
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/connections/con_some_awesome_id/scim-configuration/tokens/tok_some_awesome_id",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::DELETE);
+        assert_eq!(request.url().as_str(), test_url);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn update_custom_scripts_build_request() {
+        let management = init_api().await;
+        let options = serde_json::from_value::<ConnectionOptions>(serde_json::json!({
+            "import_mode": false,
+            "brute_force_protection": true,
+            "customScripts": {
+                "login": "function login() {}",
+                "get_user": "function getByEmail() {}"
+            }
+        }))
+        .unwrap()
+        .with_custom_scripts(CustomScripts {
+            login: Some(String::from("function login(email, password, callback) {}")),
+            ..Default::default()
+        });
+        let request = management
+            .update_custom_scripts(String::from("con_some_awesome_id"), options)
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/connections/con_some_awesome_id",
+            mockito::server_url()
+        );
+        let body = serde_json::from_slice::<serde_json::Value>(
+            request.body().unwrap().as_bytes().unwrap(),
+        )
+        .unwrap();
+        assert_eq!(request.method().as_str(), reqwest::Method::PATCH);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "options": {
+                    "import_mode": false,
+                    "brute_force_protection": true,
+                    "enabledDatabaseCustomization": true,
+                    "customScripts": {
+                        "login": "function login(email, password, callback) {}",
+                        "get_user": "function getByEmail() {}"
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn connection_options_round_trip() {
+        let options = serde_json::json!({
+            "mfa": {"active": true, "return_enroll_settings": true},
+            "import_mode": false,
+            "customScripts": {
+                "login": "function login(email, password, callback) {}",
+                "get_user": "function getByEmail(email, callback) {}"
+            },
+            "configuration": {"DB_URL": "some_awesome_db_url"},
+            "passwordPolicy": "good",
+            "disable_signup": false,
+            "brute_force_protection": true,
+            "enabledDatabaseCustomization": true
+        });
+        let parsed = serde_json::from_value::<ConnectionOptions>(options.clone()).unwrap();
+        assert_eq!(parsed.import_mode, Some(false));
+        assert_eq!(parsed.enabled_database_customization, Some(true));
+        assert_eq!(
+            parsed.custom_scripts.as_ref().unwrap().get_user.as_deref(),
+            Some("function getByEmail(email, callback) {}")
+        );
+        assert!(!parsed.other.contains_key("import_mode"));
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), options);
+    }
+
+    #[test]
+    fn custom_scripts_from_dir() {
+        let dir = std::env::temp_dir().join(format!("auth0-custom-scripts-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("login.js"), "function login() {}").unwrap();
+        std::fs::write(dir.join("get_user.js"), "function getByEmail() {}").unwrap();
+
+        let scripts = CustomScripts::from_dir(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(scripts.login, Some(String::from("function login() {}")));
+        assert_eq!(
+            scripts.get_user,
+            Some(String::from("function getByEmail() {}"))
+        );
+        assert_eq!(scripts.create, None);
+        assert_eq!(scripts.delete, None);
+    }
+}
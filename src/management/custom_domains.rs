@@ -0,0 +1,215 @@
+use super::Api;
+use crate::authentication::{self, AuthenticationMethod};
+use reqwest::{RequestBuilder, Url};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomDomainType {
+    Auth0ManagedCerts,
+    SelfManagedCerts,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomDomainStatus {
+    Disabled,
+    Pending,
+    PendingVerification,
+    Ready,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TlsPolicy {
+    Recommended,
+    Compatible,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationMethod {
+    pub name: String,
+    pub record: String,
+    pub domain: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Verification {
+    pub methods: Vec<VerificationMethod>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomDomain {
+    pub custom_domain_id: String,
+    pub domain: String,
+    pub primary: bool,
+    pub status: CustomDomainStatus,
+    #[serde(rename = "type")]
+    pub domain_type: CustomDomainType,
+    pub origin_domain_name: Option<String>,
+    pub verification: Option<Verification>,
+    pub custom_client_ip_header: Option<String>,
+    pub tls_policy: Option<TlsPolicy>,
+}
+
+impl CustomDomain {
+    /// Builds an Authentication API client for this domain once it has been verified.
+    pub fn authentication_api(
+        &self,
+        authentication: AuthenticationMethod,
+    ) -> Option<authentication::Api> {
+        if self.status != CustomDomainStatus::Ready {
+            return None;
+        }
+        let base_url = Url::parse(&format!("https://{}", self.domain)).ok()?;
+        Some(authentication::Api::init(base_url, authentication))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateCustomDomainRequestParameters {
+    pub domain: String,
+    #[serde(rename = "type")]
+    pub domain_type: CustomDomainType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_policy: Option<TlsPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_client_ip_header: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UpdateCustomDomainRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_policy: Option<TlsPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_client_ip_header: Option<String>,
+}
+
+pub trait CustomDomains {
+    fn list_custom_domains(&self) -> RequestBuilder;
+
+    fn create_custom_domain(&self, request: CreateCustomDomainRequestParameters) -> RequestBuilder;
+
+    fn get_custom_domain(&self, id: String) -> RequestBuilder;
+
+    fn update_custom_domain(
+        &self,
+        id: String,
+        request: UpdateCustomDomainRequestParameters,
+    ) -> RequestBuilder;
+
+    fn delete_custom_domain(&self, id: String) -> RequestBuilder;
+
+    fn verify_custom_domain(&self, id: String) -> RequestBuilder;
+}
+
+impl CustomDomains for Api {
+    fn list_custom_domains(&self) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/custom-domains");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn create_custom_domain(&self, request: CreateCustomDomainRequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/custom-domains");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url)).json(&request)
+    }
+
+    fn get_custom_domain(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/custom-domains/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn update_custom_domain(
+        &self,
+        id: String,
+        request: UpdateCustomDomainRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/custom-domains/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+
+    fn delete_custom_domain(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/custom-domains/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+
+    fn verify_custom_domain(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/custom-domains/{}/verify", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn create_custom_domain_build_request() {
+        let management = init_api().await;
+        let parameters = CreateCustomDomainRequestParameters {
+            domain: String::from("login.awesome.com"),
+            domain_type: CustomDomainType::Auth0ManagedCerts,
+            verification_method: Some(String::from("txt")),
+            tls_policy: None,
+            custom_client_ip_header: None,
+        };
+        let request = management.create_custom_domain(parameters).build().unwrap();
+        let test_url = format!("{}/api/v2/custom-domains", mockito::server_url());
+        let test_body = String::from(
+            "{\"domain\":\"login.awesome.com\",\
+            \"type\":\"auth0_managed_certs\",\
+            \"verification_method\":\"txt\"}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn verify_custom_domain_build_request() {
+        let management = init_api().await;
+        let request = management
+            .verify_custom_domain(String::from("cd_some_awesome_id"))
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/custom-domains/cd_some_awesome_id/verify",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+    }
+
+    #[test]
+    fn custom_domain_authentication_api() {
+        let json = r#"{
+  "custom_domain_id": "cd_some_awesome_id",
+  "domain": "login.awesome.com",
+  "primary": true,
+  "status": "pending_verification",
+  "type": "auth0_managed_certs",
+  "verification": {
+    "methods": [{"name": "cname", "record": "awesome-cd.edge.tenants.auth0.com"}]
+  }
+}"#;
+        let mut custom_domain = serde_json::from_str::<CustomDomain>(json).unwrap();
+        let authentication = || AuthenticationMethod::ClientID(String::from("some_awesome_id"));
+        assert!(custom_domain.authentication_api(authentication()).is_none());
+
+        custom_domain.status = CustomDomainStatus::Ready;
+        let api = custom_domain.authentication_api(authentication()).unwrap();
+        assert_eq!(api.base_url.as_str(), "https://login.awesome.com/");
+    }
+}
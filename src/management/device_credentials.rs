@@ -0,0 +1,153 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceCredentialType {
+    PublicKey,
+    RefreshToken,
+    RotatingRefreshToken,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceCredential {
+    pub id: String,
+    pub device_name: Option<String>,
+    pub device_id: Option<String>,
+    #[serde(rename = "type")]
+    pub credential_type: DeviceCredentialType,
+    pub user_id: Option<String>,
+    pub client_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ListDeviceCredentialsRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_totals: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_fields: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub credential_type: Option<DeviceCredentialType>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateDeviceCredentialRequestParameters {
+    pub device_name: String,
+    #[serde(rename = "type")]
+    pub credential_type: DeviceCredentialType,
+    pub value: String,
+    pub device_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+}
+
+pub trait DeviceCredentials {
+    fn list_device_credentials(
+        &self,
+        request: ListDeviceCredentialsRequestParameters,
+    ) -> RequestBuilder;
+
+    fn create_device_credential(
+        &self,
+        request: CreateDeviceCredentialRequestParameters,
+    ) -> RequestBuilder;
+
+    fn delete_device_credential(&self, id: String) -> RequestBuilder;
+}
+
+impl DeviceCredentials for Api {
+    fn list_device_credentials(
+        &self,
+        request: ListDeviceCredentialsRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/device-credentials");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+
+    fn create_device_credential(
+        &self,
+        request: CreateDeviceCredentialRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/device-credentials");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url)).json(&request)
+    }
+
+    fn delete_device_credential(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/device-credentials/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn list_device_credentials_build_request() {
+        let management = init_api().await;
+        let parameters = ListDeviceCredentialsRequestParameters {
+            per_page: None,
+            page: None,
+            include_totals: None,
+            fields: None,
+            include_fields: None,
+            user_id: Some(String::from("some_awesome_user")),
+            client_id: None,
+            credential_type: Some(DeviceCredentialType::RefreshToken),
+        };
+        let request = management
+            .list_device_credentials(parameters)
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/device-credentials?user_id=some_awesome_user&type=refresh_token",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::GET);
+        assert_eq!(request.url().as_str(), test_url);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn create_device_credential_build_request() {
+        let management = init_api().await;
+        let parameters = CreateDeviceCredentialRequestParameters {
+            device_name: String::from("some_awesome_device"),
+            credential_type: DeviceCredentialType::PublicKey,
+            value: String::from("some_awesome_public_key"),
+            device_id: String::from("some_awesome_device_id"),
+            client_id: None,
+        };
+        let request = management
+            .create_device_credential(parameters)
+            .build()
+            .unwrap();
+        let test_url = format!("{}/api/v2/device-credentials", mockito::server_url());
+        let test_body = String::from(
+            "{\"device_name\":\"some_awesome_device\",\
+            \"type\":\"public_key\",\
+            \"value\":\"some_awesome_public_key\",\
+            \"device_id\":\"some_awesome_device_id\"}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailTemplateName {
+    VerifyEmail,
+    VerifyEmailByCode,
+    ResetEmail,
+    ResetEmailByCode,
+    WelcomeEmail,
+    BlockedAccount,
+    StolenCredentials,
+    EnrollmentEmail,
+    MfaOobCode,
+    UserInvitation,
+    ChangePassword,
+    PasswordReset,
+    AsyncApproval,
+}
+
+impl EmailTemplateName {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailTemplateName::VerifyEmail => "verify_email",
+            EmailTemplateName::VerifyEmailByCode => "verify_email_by_code",
+            EmailTemplateName::ResetEmail => "reset_email",
+            EmailTemplateName::ResetEmailByCode => "reset_email_by_code",
+            EmailTemplateName::WelcomeEmail => "welcome_email",
+            EmailTemplateName::BlockedAccount => "blocked_account",
+            EmailTemplateName::StolenCredentials => "stolen_credentials",
+            EmailTemplateName::EnrollmentEmail => "enrollment_email",
+            EmailTemplateName::MfaOobCode => "mfa_oob_code",
+            EmailTemplateName::UserInvitation => "user_invitation",
+            EmailTemplateName::ChangePassword => "change_password",
+            EmailTemplateName::PasswordReset => "password_reset",
+            EmailTemplateName::AsyncApproval => "async_approval",
+        }
+    }
+}
+
+impl fmt::Display for EmailTemplateName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailTemplate {
+    pub template: EmailTemplateName,
+    pub body: Option<String>,
+    pub from: Option<String>,
+    pub result_url: Option<String>,
+    pub subject: Option<String>,
+    pub syntax: Option<String>,
+    pub url_lifetime_in_seconds: Option<u64>,
+    pub include_email_in_redirect: Option<bool>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateEmailTemplateRequestParameters {
+    pub template: EmailTemplateName,
+    pub body: String,
+    pub from: String,
+    pub subject: String,
+    pub syntax: String,
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_lifetime_in_seconds: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_email_in_redirect: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEmailTemplateRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub syntax: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_lifetime_in_seconds: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_email_in_redirect: Option<bool>,
+}
+
+pub trait EmailTemplates {
+    fn get_email_template(&self, template_name: EmailTemplateName) -> RequestBuilder;
+
+    fn create_email_template(
+        &self,
+        request: CreateEmailTemplateRequestParameters,
+    ) -> RequestBuilder;
+
+    fn update_email_template(
+        &self,
+        template_name: EmailTemplateName,
+        request: UpdateEmailTemplateRequestParameters,
+    ) -> RequestBuilder;
+
+    fn set_email_template(
+        &self,
+        template_name: EmailTemplateName,
+        request: CreateEmailTemplateRequestParameters,
+    ) -> RequestBuilder;
+}
+
+impl EmailTemplates for Api {
+    fn get_email_template(&self, template_name: EmailTemplateName) -> RequestBuilder {
+        let endpoint = format!("/api/v2/email-templates/{}", template_name);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn create_email_template(
+        &self,
+        request: CreateEmailTemplateRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/email-templates");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url)).json(&request)
+    }
+
+    fn update_email_template(
+        &self,
+        template_name: EmailTemplateName,
+        request: UpdateEmailTemplateRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/email-templates/{}", template_name);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+
+    fn set_email_template(
+        &self,
+        template_name: EmailTemplateName,
+        request: CreateEmailTemplateRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/email-templates/{}", template_name);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.put(url)).json(&request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_email_template_build_request() {
+        let management = init_api().await;
+        let request = management
+            .get_email_template(EmailTemplateName::VerifyEmail)
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/email-templates/verify_email",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::GET);
+        assert_eq!(request.url().as_str(), test_url);
+        assert!(request.body().is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn update_email_template_build_request() {
+        let management = init_api().await;
+        let parameters = UpdateEmailTemplateRequestParameters {
+            body: Some(String::from("<html>some_awesome_body</html>")),
+            from: None,
+            subject: Some(String::from("some_awesome_subject")),
+            syntax: None,
+            enabled: None,
+            result_url: Some(String::from("some_awesome_result_url")),
+            url_lifetime_in_seconds: Some(3600),
+            include_email_in_redirect: None,
+        };
+        let request = management
+            .update_email_template(EmailTemplateName::ResetEmail, parameters)
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/email-templates/reset_email",
+            mockito::server_url()
+        );
+        let test_body = String::from(
+            "{\"body\":\"<html>some_awesome_body</html>\",\
+            \"subject\":\"some_awesome_subject\",\
+            \"resultUrl\":\"some_awesome_result_url\",\
+            \"urlLifetimeInSeconds\":3600}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::PATCH);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+}
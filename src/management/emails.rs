@@ -0,0 +1,216 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailProviderName {
+    Mandrill,
+    Sendgrid,
+    Ses,
+    Sparkpost,
+    Mailgun,
+    Smtp,
+    AzureCs,
+    Ms365,
+    Custom,
+    /// A provider added to Auth0 after this version.
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EmailProviderCredentials {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    #[serde(rename = "accessKeyId", skip_serializing_if = "Option::is_none")]
+    pub access_key_id: Option<String>,
+    #[serde(rename = "secretAccessKey", skip_serializing_if = "Option::is_none")]
+    pub secret_access_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_host: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_port: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_pass: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailProvider {
+    pub name: EmailProviderName,
+    pub enabled: Option<bool>,
+    pub default_from_address: Option<String>,
+    pub credentials: Option<EmailProviderCredentials>,
+    pub settings: Option<HashMap<String, serde_json::Value>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct GetEmailProviderRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_fields: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ConfigureEmailProviderRequestParameters {
+    pub name: EmailProviderName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_from_address: Option<String>,
+    pub credentials: EmailProviderCredentials,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<HashMap<String, serde_json::Value>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UpdateEmailProviderRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<EmailProviderName>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_from_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<EmailProviderCredentials>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<HashMap<String, serde_json::Value>>,
+}
+
+pub trait Emails {
+    fn get_email_provider(&self, request: GetEmailProviderRequestParameters) -> RequestBuilder;
+
+    fn configure_email_provider(
+        &self,
+        request: ConfigureEmailProviderRequestParameters,
+    ) -> RequestBuilder;
+
+    fn update_email_provider(
+        &self,
+        request: UpdateEmailProviderRequestParameters,
+    ) -> RequestBuilder;
+
+    fn delete_email_provider(&self) -> RequestBuilder;
+}
+
+impl Emails for Api {
+    fn get_email_provider(&self, request: GetEmailProviderRequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/emails/provider");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+
+    fn configure_email_provider(
+        &self,
+        request: ConfigureEmailProviderRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/emails/provider");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url)).json(&request)
+    }
+
+    fn update_email_provider(
+        &self,
+        request: UpdateEmailProviderRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/emails/provider");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+
+    fn delete_email_provider(&self) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/emails/provider");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn configure_email_provider_build_request() {
+        let management = init_api().await;
+        let parameters = ConfigureEmailProviderRequestParameters {
+            name: EmailProviderName::Ses,
+            enabled: Some(true),
+            default_from_address: None,
+            credentials: EmailProviderCredentials {
+                access_key_id: Some(String::from("some_awesome_access_key_id")),
+                secret_access_key: Some(String::from("some_awesome_secret_access_key")),
+                region: Some(String::from("us-east-1")),
+                ..Default::default()
+            },
+            settings: None,
+        };
+        let request = management
+            .configure_email_provider(parameters)
+            .build()
+            .unwrap();
+        let test_url = format!("{}/api/v2/emails/provider", mockito::server_url());
+        let test_body = String::from(
+            "{\"name\":\"ses\",\
+            \"enabled\":true,\
+            \"credentials\":{\
+            \"accessKeyId\":\"some_awesome_access_key_id\",\
+            \"secretAccessKey\":\"some_awesome_secret_access_key\",\
+            \"region\":\"us-east-1\"}}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[test]
+    fn email_provider_deserialize() {
+        let provider = serde_json::from_value::<EmailProvider>(serde_json::json!({
+            "name": "azure_cs",
+            "enabled": true
+        }))
+        .unwrap();
+        assert_eq!(provider.name, EmailProviderName::AzureCs);
+        let provider = serde_json::from_value::<EmailProvider>(serde_json::json!({
+            "name": "some_awesome_provider",
+            "enabled": true
+        }))
+        .unwrap();
+        assert_eq!(
+            provider.name,
+            EmailProviderName::Other(String::from("some_awesome_provider"))
+        );
+        assert_eq!(
+            serde_json::to_value(&provider.name).unwrap(),
+            "some_awesome_provider"
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_email_provider_build_request() {
+        let management = init_api().await;
+        let parameters = GetEmailProviderRequestParameters {
+            fields: Some(String::from("name,enabled")),
+            include_fields: Some(true),
+        };
+        let request = management.get_email_provider(parameters).build().unwrap();
+        let test_url = format!(
+            "{}/api/v2/emails/provider?fields=name%2Cenabled&include_fields=true",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::GET);
+        assert_eq!(request.url().as_str(), test_url);
+        assert!(request.body().is_none());
+    }
+}
@@ -0,0 +1,426 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The integration a [`FlowAction`] belongs to.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FlowActionType {
+    Activecampaign,
+    Airtable,
+    Auth0,
+    Bigquery,
+    Clearbit,
+    Email,
+    Flow,
+    GoogleSheets,
+    #[default]
+    Http,
+    Hubspot,
+    Json,
+    Jwt,
+    Mailchimp,
+    Mailjet,
+    Otp,
+    Pipedrive,
+    Salesforce,
+    Sendgrid,
+    Slack,
+    Stripe,
+    Telegram,
+    Twilio,
+    Whatsapp,
+    Xml,
+    Zapier,
+    /// An integration this crate doesn't know about yet.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A step of a flow: the `action` of an integration `type`, e.g. `SEND_REQUEST` of `HTTP`,
+/// configured by `params`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FlowAction {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub action_type: FlowActionType,
+    pub action: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_failure: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_output: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<serde_json::Value>,
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Flow {
+    pub id: String,
+    pub name: String,
+    pub actions: Option<Vec<FlowAction>>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub executed_at: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FlowExecutionStatus {
+    Pending,
+    Running,
+    Suspended,
+    Completed,
+    Failed,
+    Canceled,
+    /// A status this crate doesn't know about yet.
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlowExecution {
+    pub id: String,
+    pub trace_id: String,
+    pub journey_id: Option<String>,
+    pub status: FlowExecutionStatus,
+    pub debug: Option<HashMap<String, serde_json::Value>>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub started_at: Option<String>,
+    pub ended_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlowExecutions {
+    pub executions: Vec<FlowExecution>,
+    pub next: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VaultConnection {
+    pub id: String,
+    pub app_id: String,
+    pub name: String,
+    pub account_name: Option<String>,
+    pub ready: Option<bool>,
+    pub fingerprint: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub refreshed_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ListFlowsRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_totals: Option<bool>,
+    /// Comma separated list of properties to hydrate, e.g. `form_count`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hydrate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub synchronous: Option<bool>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct FlowRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<FlowAction>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ListFlowExecutionsRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take: Option<u16>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ListVaultConnectionsRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_totals: Option<bool>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct VaultConnectionRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<String>,
+    /// Connection setup, e.g. `{"type": "API_KEY", "api_key": "..."}`; its shape depends on
+    /// `app_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup: Option<serde_json::Value>,
+}
+
+pub trait Flows {
+    fn list_flows(&self, request: ListFlowsRequestParameters) -> RequestBuilder;
+
+    fn create_flow(&self, request: FlowRequestParameters) -> RequestBuilder;
+
+    fn get_flow(&self, id: String) -> RequestBuilder;
+
+    fn update_flow(&self, id: String, request: FlowRequestParameters) -> RequestBuilder;
+
+    fn delete_flow(&self, id: String) -> RequestBuilder;
+
+    fn list_flow_executions(
+        &self,
+        id: String,
+        request: ListFlowExecutionsRequestParameters,
+    ) -> RequestBuilder;
+
+    fn get_flow_execution(&self, id: String, execution_id: String) -> RequestBuilder;
+
+    fn delete_flow_execution(&self, id: String, execution_id: String) -> RequestBuilder;
+
+    fn list_vault_connections(
+        &self,
+        request: ListVaultConnectionsRequestParameters,
+    ) -> RequestBuilder;
+
+    fn create_vault_connection(&self, request: VaultConnectionRequestParameters) -> RequestBuilder;
+
+    fn get_vault_connection(&self, id: String) -> RequestBuilder;
+
+    fn update_vault_connection(
+        &self,
+        id: String,
+        request: VaultConnectionRequestParameters,
+    ) -> RequestBuilder;
+
+    fn delete_vault_connection(&self, id: String) -> RequestBuilder;
+}
+
+impl Flows for Api {
+    fn list_flows(&self, request: ListFlowsRequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/flows");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+
+    fn create_flow(&self, request: FlowRequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/flows");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url)).json(&request)
+    }
+
+    fn get_flow(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/flows/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn update_flow(&self, id: String, request: FlowRequestParameters) -> RequestBuilder {
+        let endpoint = format!("/api/v2/flows/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+
+    fn delete_flow(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/flows/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+
+    fn list_flow_executions(
+        &self,
+        id: String,
+        request: ListFlowExecutionsRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/flows/{}/executions", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+
+    fn get_flow_execution(&self, id: String, execution_id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/flows/{}/executions/{}", id, execution_id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn delete_flow_execution(&self, id: String, execution_id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/flows/{}/executions/{}", id, execution_id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+
+    fn list_vault_connections(
+        &self,
+        request: ListVaultConnectionsRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/flows/vault/connections");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+
+    fn create_vault_connection(&self, request: VaultConnectionRequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/flows/vault/connections");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url)).json(&request)
+    }
+
+    fn get_vault_connection(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/flows/vault/connections/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn update_vault_connection(
+        &self,
+        id: String,
+        request: VaultConnectionRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/flows/vault/connections/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+
+    fn delete_vault_connection(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/flows/vault/connections/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn create_flow_build_request() {
+        let management = init_api().await;
+        let parameters = FlowRequestParameters {
+            name: Some(String::from("some_awesome_flow")),
+            actions: Some(vec![FlowAction {
+                id: String::from("http_request_1"),
+                action_type: FlowActionType::Http,
+                action: String::from("SEND_REQUEST"),
+                params: Some(serde_json::json!({"url": "https://some_awesome_url"})),
+                ..Default::default()
+            }]),
+        };
+        let request = management.create_flow(parameters).build().unwrap();
+        let test_url = format!("{}/api/v2/flows", mockito::server_url());
+        let test_body = String::from(
+            "{\"name\":\"some_awesome_flow\",\
+            \"actions\":[{\"id\":\"http_request_1\",\"type\":\"HTTP\",\"action\":\"SEND_REQUEST\",\
+            \"params\":{\"url\":\"https://some_awesome_url\"}}]}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[test]
+    fn flow_deserialize() {
+        let flow = serde_json::from_value::<Flow>(serde_json::json!({
+            "id": "af_some_awesome_id",
+            "name": "some_awesome_flow",
+            "actions": [
+                {"id": "http_request_1", "type": "HTTP", "action": "SEND_REQUEST"},
+                {"id": "google_sheets_1", "type": "GOOGLE_SHEETS", "action": "ADD_ROW"},
+                {"id": "some_awesome_action", "type": "SOME_AWESOME_TYPE", "action": "RUN"}
+            ]
+        }))
+        .unwrap();
+        let action_types = flow
+            .actions
+            .unwrap()
+            .into_iter()
+            .map(|action| action.action_type)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            action_types,
+            vec![
+                FlowActionType::Http,
+                FlowActionType::GoogleSheets,
+                FlowActionType::Other(String::from("SOME_AWESOME_TYPE")),
+            ]
+        );
+    }
+
+    #[test]
+    fn flow_execution_deserialize() {
+        let execution = serde_json::from_value::<FlowExecution>(serde_json::json!({
+            "id": "afe_some_awesome_id",
+            "trace_id": "some_awesome_trace_id",
+            "status": "completed"
+        }))
+        .unwrap();
+        assert_eq!(execution.status, FlowExecutionStatus::Completed);
+        let execution = serde_json::from_value::<FlowExecution>(serde_json::json!({
+            "id": "afe_some_awesome_id",
+            "trace_id": "some_awesome_trace_id",
+            "status": "some_awesome_status"
+        }))
+        .unwrap();
+        assert_eq!(
+            execution.status,
+            FlowExecutionStatus::Other(String::from("some_awesome_status"))
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn list_flow_executions_build_request() {
+        let management = init_api().await;
+        let parameters = ListFlowExecutionsRequestParameters {
+            from: Some(String::from("some_awesome_checkpoint")),
+            take: Some(10),
+        };
+        let request = management
+            .list_flow_executions(String::from("af_some_awesome_id"), parameters)
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/flows/af_some_awesome_id/executions?from=some_awesome_checkpoint&take=10",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::GET);
+        assert_eq!(request.url().as_str(), test_url);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn create_vault_connection_build_request() {
+        let management = init_api().await;
+        let parameters = VaultConnectionRequestParameters {
+            name: Some(String::from("some_awesome_connection")),
+            app_id: Some(String::from("HTTP")),
+            setup: Some(serde_json::json!({"type": "BEARER", "token": "some_awesome_token"})),
+        };
+        let request = management
+            .create_vault_connection(parameters)
+            .build()
+            .unwrap();
+        let test_url = format!("{}/api/v2/flows/vault/connections", mockito::server_url());
+        let test_body = String::from(
+            "{\"name\":\"some_awesome_connection\",\
+            \"app_id\":\"HTTP\",\
+            \"setup\":{\"token\":\"some_awesome_token\",\"type\":\"BEARER\"}}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+}
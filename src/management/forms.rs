@@ -0,0 +1,265 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FormLanguages {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FormMessages {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct FormCoordinates {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FormNodeType {
+    /// Components shown to the user.
+    #[default]
+    Step,
+    /// A flow to run.
+    Flow,
+    /// Rules choosing the next node.
+    Router,
+    /// A node type this crate doesn't know about yet.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A node of the form graph. Its `config` depends on the `type`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FormNode {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub node_type: FormNodeType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coordinates: Option<FormCoordinates>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<serde_json::Value>,
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FormStart {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_node: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coordinates: Option<FormCoordinates>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden_fields: Option<Vec<serde_json::Value>>,
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FormEnding {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirection: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_submit: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coordinates: Option<FormCoordinates>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resume_flow: Option<bool>,
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FormStyle {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub css: Option<String>,
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Form {
+    pub id: String,
+    pub name: String,
+    pub messages: Option<FormMessages>,
+    pub languages: Option<FormLanguages>,
+    pub translations: Option<HashMap<String, serde_json::Value>>,
+    pub nodes: Option<Vec<FormNode>>,
+    pub start: Option<FormStart>,
+    pub ending: Option<FormEnding>,
+    pub style: Option<FormStyle>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub embedded_at: Option<String>,
+    pub submitted_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ListFormsRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_totals: Option<bool>,
+    /// Comma separated list of properties to hydrate, e.g. `flow_count,links`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hydrate: Option<String>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct FormRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messages: Option<FormMessages>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub languages: Option<FormLanguages>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translations: Option<HashMap<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<FormNode>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<FormStart>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending: Option<FormEnding>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<FormStyle>,
+}
+
+pub trait Forms {
+    fn list_forms(&self, request: ListFormsRequestParameters) -> RequestBuilder;
+
+    fn create_form(&self, request: FormRequestParameters) -> RequestBuilder;
+
+    fn get_form(&self, id: String) -> RequestBuilder;
+
+    fn update_form(&self, id: String, request: FormRequestParameters) -> RequestBuilder;
+
+    fn delete_form(&self, id: String) -> RequestBuilder;
+}
+
+impl Forms for Api {
+    fn list_forms(&self, request: ListFormsRequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/forms");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+
+    fn create_form(&self, request: FormRequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/forms");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url)).json(&request)
+    }
+
+    fn get_form(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/forms/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn update_form(&self, id: String, request: FormRequestParameters) -> RequestBuilder {
+        let endpoint = format!("/api/v2/forms/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+
+    fn delete_form(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/forms/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn create_form_build_request() {
+        let management = init_api().await;
+        let parameters = FormRequestParameters {
+            name: Some(String::from("some_awesome_form")),
+            nodes: Some(vec![FormNode {
+                id: String::from("step_1"),
+                node_type: FormNodeType::Step,
+                config: Some(serde_json::json!({"next_node": "$ending"})),
+                ..Default::default()
+            }]),
+            start: Some(FormStart {
+                next_node: Some(String::from("step_1")),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let request = management.create_form(parameters).build().unwrap();
+        let test_url = format!("{}/api/v2/forms", mockito::server_url());
+        let test_body = String::from(
+            "{\"name\":\"some_awesome_form\",\
+            \"nodes\":[{\"id\":\"step_1\",\"type\":\"STEP\",\"config\":{\"next_node\":\"$ending\"}}],\
+            \"start\":{\"next_node\":\"step_1\"}}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[test]
+    fn form_deserialize() {
+        let form = serde_json::from_value::<Form>(serde_json::json!({
+            "id": "ap_some_awesome_id",
+            "name": "some_awesome_form",
+            "start": {"next_node": "step_1", "coordinates": {"x": 0, "y": 0}},
+            "nodes": [{
+                "id": "step_1",
+                "type": "STEP",
+                "coordinates": {"x": 500, "y": 0},
+                "alias": "Some awesome step",
+                "config": {"components": [], "next_node": "$ending"}
+            }],
+            "ending": {"resume_flow": true, "coordinates": {"x": 1250, "y": 0}},
+            "style": {"css": "h1 {}", "theme": "some_awesome_theme"}
+        }))
+        .unwrap();
+        let node = &form.nodes.unwrap()[0];
+        assert_eq!(node.node_type, FormNodeType::Step);
+        assert_eq!(node.coordinates, Some(FormCoordinates { x: 500, y: 0 }));
+        assert_eq!(node.config.as_ref().unwrap()["next_node"], "$ending");
+        assert_eq!(form.start.unwrap().next_node.as_deref(), Some("step_1"));
+        assert_eq!(form.ending.unwrap().resume_flow, Some(true));
+        let style = form.style.unwrap();
+        assert_eq!(style.css.as_deref(), Some("h1 {}"));
+        assert_eq!(style.other["theme"], "some_awesome_theme");
+    }
+
+    #[test]
+    fn form_node_type_other() {
+        let node_type =
+            serde_json::from_value::<FormNodeType>(serde_json::json!("SOME_AWESOME_TYPE")).unwrap();
+        assert_eq!(
+            node_type,
+            FormNodeType::Other(String::from("SOME_AWESOME_TYPE"))
+        );
+        assert_eq!(
+            serde_json::to_value(&node_type).unwrap(),
+            serde_json::json!("SOME_AWESOME_TYPE")
+        );
+    }
+}
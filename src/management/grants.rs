@@ -0,0 +1,110 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Grant {
+    pub id: String,
+    #[serde(rename = "clientID")]
+    pub client_id: String,
+    pub user_id: String,
+    pub audience: String,
+    pub scope: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ListGrantsRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_totals: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DeleteGrantsByUserRequestParameters {
+    pub user_id: String,
+}
+
+pub trait Grants {
+    fn list_grants(&self, request: ListGrantsRequestParameters) -> RequestBuilder;
+
+    fn delete_grant(&self, id: String) -> RequestBuilder;
+
+    fn delete_grants_by_user(&self, request: DeleteGrantsByUserRequestParameters)
+        -> RequestBuilder;
+}
+
+impl Grants for Api {
+    fn list_grants(&self, request: ListGrantsRequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/grants");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+
+    fn delete_grant(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/grants/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+
+    fn delete_grants_by_user(
+        &self,
+        request: DeleteGrantsByUserRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/grants");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url)).query(&request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn list_grants_build_request() {
+        let management = init_api().await;
+        let parameters = ListGrantsRequestParameters {
+            per_page: Some(50),
+            page: None,
+            include_totals: None,
+            user_id: Some(String::from("auth0|some_awesome_user")),
+            client_id: None,
+            audience: None,
+        };
+        let request = management.list_grants(parameters).build().unwrap();
+        let test_url = format!(
+            "{}/api/v2/grants?per_page=50&user_id=auth0%7Csome_awesome_user",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::GET);
+        assert_eq!(request.url().as_str(), test_url);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn delete_grants_by_user_build_request() {
+        let management = init_api().await;
+        let parameters = DeleteGrantsByUserRequestParameters {
+            user_id: String::from("auth0|some_awesome_user"),
+        };
+        let request = management
+            .delete_grants_by_user(parameters)
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/grants?user_id=auth0%7Csome_awesome_user",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::DELETE);
+        assert_eq!(request.url().as_str(), test_url);
+    }
+}
@@ -0,0 +1,93 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SigningKey {
+    pub kid: String,
+    pub cert: String,
+    pub pkcs7: Option<String>,
+    pub current: Option<bool>,
+    pub next: Option<bool>,
+    pub previous: Option<bool>,
+    pub current_since: Option<String>,
+    pub current_until: Option<String>,
+    pub fingerprint: Option<String>,
+    pub thumbprint: Option<String>,
+    pub revoked: Option<bool>,
+    pub revoked_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RotatedSigningKey {
+    pub kid: String,
+    pub cert: String,
+}
+
+pub trait Keys {
+    fn list_signing_keys(&self) -> RequestBuilder;
+
+    fn get_signing_key(&self, kid: String) -> RequestBuilder;
+
+    fn rotate_signing_key(&self) -> RequestBuilder;
+
+    fn revoke_signing_key(&self, kid: String) -> RequestBuilder;
+}
+
+impl Keys for Api {
+    fn list_signing_keys(&self) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/keys/signing");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn get_signing_key(&self, kid: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/keys/signing/{}", kid);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn rotate_signing_key(&self) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/keys/signing/rotate");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url))
+    }
+
+    fn revoke_signing_key(&self, kid: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/keys/signing/{}/revoke", kid);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.put(url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn rotate_signing_key_build_request() {
+        let management = init_api().await;
+        let request = management.rotate_signing_key().build().unwrap();
+        let test_url = format!("{}/api/v2/keys/signing/rotate", mockito::server_url());
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+        assert!(request.body().is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn revoke_signing_key_build_request() {
+        let management = init_api().await;
+        let request = management
+            .revoke_signing_key(String::from("some_awesome_kid"))
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/keys/signing/some_awesome_kid/revoke",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::PUT);
+        assert_eq!(request.url().as_str(), test_url);
+        assert!(request.body().is_none());
+    }
+}
@@ -0,0 +1,211 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PromptName {
+    Login,
+    LoginId,
+    LoginPassword,
+    LoginPasswordless,
+    LoginEmailVerification,
+    Signup,
+    SignupId,
+    SignupPassword,
+    ResetPassword,
+    Consent,
+    Logout,
+    MfaPush,
+    MfaOtp,
+    MfaVoice,
+    MfaPhone,
+    MfaWebauthn,
+    MfaSms,
+    MfaEmail,
+    MfaRecoveryCode,
+    Mfa,
+    Status,
+    DeviceFlow,
+    EmailVerification,
+    EmailOtpChallenge,
+    Organizations,
+    Invitation,
+    Common,
+}
+
+impl PromptName {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PromptName::Login => "login",
+            PromptName::LoginId => "login-id",
+            PromptName::LoginPassword => "login-password",
+            PromptName::LoginPasswordless => "login-passwordless",
+            PromptName::LoginEmailVerification => "login-email-verification",
+            PromptName::Signup => "signup",
+            PromptName::SignupId => "signup-id",
+            PromptName::SignupPassword => "signup-password",
+            PromptName::ResetPassword => "reset-password",
+            PromptName::Consent => "consent",
+            PromptName::Logout => "logout",
+            PromptName::MfaPush => "mfa-push",
+            PromptName::MfaOtp => "mfa-otp",
+            PromptName::MfaVoice => "mfa-voice",
+            PromptName::MfaPhone => "mfa-phone",
+            PromptName::MfaWebauthn => "mfa-webauthn",
+            PromptName::MfaSms => "mfa-sms",
+            PromptName::MfaEmail => "mfa-email",
+            PromptName::MfaRecoveryCode => "mfa-recovery-code",
+            PromptName::Mfa => "mfa",
+            PromptName::Status => "status",
+            PromptName::DeviceFlow => "device-flow",
+            PromptName::EmailVerification => "email-verification",
+            PromptName::EmailOtpChallenge => "email-otp-challenge",
+            PromptName::Organizations => "organizations",
+            PromptName::Invitation => "invitation",
+            PromptName::Common => "common",
+        }
+    }
+}
+
+impl fmt::Display for PromptName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UniversalLoginExperience {
+    New,
+    Classic,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptSettings {
+    pub universal_login_experience: Option<UniversalLoginExperience>,
+    pub identifier_first: Option<bool>,
+    pub webauthn_platform_first_factor: Option<bool>,
+}
+
+/// Custom text for a prompt, keyed by screen name and then by text key.
+pub type CustomText = HashMap<String, HashMap<String, String>>;
+
+#[derive(Serialize, Deserialize)]
+pub struct UpdatePromptSettingsRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub universal_login_experience: Option<UniversalLoginExperience>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier_first: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webauthn_platform_first_factor: Option<bool>,
+}
+
+pub trait Prompts {
+    fn get_prompt_settings(&self) -> RequestBuilder;
+
+    fn update_prompt_settings(
+        &self,
+        request: UpdatePromptSettingsRequestParameters,
+    ) -> RequestBuilder;
+
+    fn get_custom_text(&self, prompt: PromptName, language: String) -> RequestBuilder;
+
+    fn set_custom_text(
+        &self,
+        prompt: PromptName,
+        language: String,
+        request: CustomText,
+    ) -> RequestBuilder;
+}
+
+impl Prompts for Api {
+    fn get_prompt_settings(&self) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/prompts");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn update_prompt_settings(
+        &self,
+        request: UpdatePromptSettingsRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/prompts");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+
+    fn get_custom_text(&self, prompt: PromptName, language: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/prompts/{}/custom-text/{}", prompt, language);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn set_custom_text(
+        &self,
+        prompt: PromptName,
+        language: String,
+        request: CustomText,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/prompts/{}/custom-text/{}", prompt, language);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.put(url)).json(&request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn update_prompt_settings_build_request() {
+        let management = init_api().await;
+        let parameters = UpdatePromptSettingsRequestParameters {
+            universal_login_experience: Some(UniversalLoginExperience::New),
+            identifier_first: Some(true),
+            webauthn_platform_first_factor: None,
+        };
+        let request = management
+            .update_prompt_settings(parameters)
+            .build()
+            .unwrap();
+        let test_url = format!("{}/api/v2/prompts", mockito::server_url());
+        let test_body = String::from(
+            "{\"universal_login_experience\":\"new\",\
+            \"identifier_first\":true}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::PATCH);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn set_custom_text_build_request() {
+        let management = init_api().await;
+        let mut texts = HashMap::new();
+        texts.insert(String::from("title"), String::from("some_awesome_title"));
+        let mut parameters = CustomText::new();
+        parameters.insert(String::from("login-id"), texts);
+        let request = management
+            .set_custom_text(PromptName::LoginId, String::from("en"), parameters)
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/prompts/login-id/custom-text/en",
+            mockito::server_url()
+        );
+        let test_body = String::from("{\"login-id\":{\"title\":\"some_awesome_title\"}}");
+        assert_eq!(request.method().as_str(), reqwest::Method::PUT);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserAttribute {
+    pub name: String,
+    pub description: String,
+    pub is_optional: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SelfServiceProfileBranding {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colors: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelfServiceProfile {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub user_attributes: Option<Vec<UserAttribute>>,
+    pub branding: Option<SelfServiceProfileBranding>,
+    pub allowed_strategies: Option<Vec<String>>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ListSelfServiceProfilesRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_totals: Option<bool>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct SelfServiceProfileRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_attributes: Option<Vec<UserAttribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branding: Option<SelfServiceProfileBranding>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_strategies: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SsoTicketConnectionConfig {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_domain_connection: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_as_button: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SsoTicketEnabledOrganization {
+    pub organization_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assign_membership_on_login: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_as_button: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DomainVerification {
+    None,
+    Optional,
+    Required,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DomainAliasesConfig {
+    pub domain_verification: DomainVerification,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct CreateSsoTicketRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_config: Option<SsoTicketConnectionConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_clients: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_organizations: Option<Vec<SsoTicketEnabledOrganization>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl_sec: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_aliases_config: Option<DomainAliasesConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SsoTicket {
+    pub ticket: String,
+}
+
+pub trait SelfServiceProfiles {
+    fn list_self_service_profiles(
+        &self,
+        request: ListSelfServiceProfilesRequestParameters,
+    ) -> RequestBuilder;
+
+    fn create_self_service_profile(
+        &self,
+        request: SelfServiceProfileRequestParameters,
+    ) -> RequestBuilder;
+
+    fn get_self_service_profile(&self, id: String) -> RequestBuilder;
+
+    fn update_self_service_profile(
+        &self,
+        id: String,
+        request: SelfServiceProfileRequestParameters,
+    ) -> RequestBuilder;
+
+    fn delete_self_service_profile(&self, id: String) -> RequestBuilder;
+
+    fn create_sso_ticket(
+        &self,
+        id: String,
+        request: CreateSsoTicketRequestParameters,
+    ) -> RequestBuilder;
+
+    fn revoke_sso_ticket(&self, id: String, ticket_id: String) -> RequestBuilder;
+}
+
+impl SelfServiceProfiles for Api {
+    fn list_self_service_profiles(
+        &self,
+        request: ListSelfServiceProfilesRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/self-service-profiles");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+
+    fn create_self_service_profile(
+        &self,
+        request: SelfServiceProfileRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/self-service-profiles");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url)).json(&request)
+    }
+
+    fn get_self_service_profile(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/self-service-profiles/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn update_self_service_profile(
+        &self,
+        id: String,
+        request: SelfServiceProfileRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/self-service-profiles/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+
+    fn delete_self_service_profile(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/self-service-profiles/{}", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+
+    fn create_sso_ticket(
+        &self,
+        id: String,
+        request: CreateSsoTicketRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/self-service-profiles/{}/sso-ticket", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url)).json(&request)
+    }
+
+    fn revoke_sso_ticket(&self, id: String, ticket_id: String) -> RequestBuilder {
+        let endpoint = format!(
+            "/api/v2/self-service-profiles/{}/sso-ticket/{}/revoke",
+            id, ticket_id
+        );
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.post(url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn create_sso_ticket_build_request() {
+        let management = init_api().await;
+        let parameters = CreateSsoTicketRequestParameters {
+            connection_config: Some(SsoTicketConnectionConfig {
+                name: String::from("some-awesome-customer"),
+                ..Default::default()
+            }),
+            enabled_clients: Some(vec![String::from("some_awesome_client_id")]),
+            ttl_sec: Some(86400),
+            domain_aliases_config: Some(DomainAliasesConfig {
+                domain_verification: DomainVerification::Required,
+            }),
+            ..Default::default()
+        };
+        let request = management
+            .create_sso_ticket(String::from("ssp_some_awesome_id"), parameters)
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/self-service-profiles/ssp_some_awesome_id/sso-ticket",
+            mockito::server_url()
+        );
+        let test_body = String::from(
+            "{\"connection_config\":{\"name\":\"some-awesome-customer\"},\
+            \"enabled_clients\":[\"some_awesome_client_id\"],\
+            \"ttl_sec\":86400,\
+            \"domain_aliases_config\":{\"domain_verification\":\"required\"}}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn revoke_sso_ticket_build_request() {
+        let management = init_api().await;
+        let request = management
+            .revoke_sso_ticket(
+                String::from("ssp_some_awesome_id"),
+                String::from("some_awesome_ticket_id"),
+            )
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/self-service-profiles/ssp_some_awesome_id/sso-ticket/some_awesome_ticket_id/revoke",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::POST);
+        assert_eq!(request.url().as_str(), test_url);
+    }
+}
@@ -0,0 +1,83 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+/// Response body of `/api/v2/stats/active-users`.
+pub type ActiveUsers = u64;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyStats {
+    pub date: String,
+    pub logins: u64,
+    pub signups: u64,
+    pub leaked_passwords: u64,
+    pub updated_at: Option<String>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct GetDailyStatsRequestParameters {
+    /// Optional first day of the date range (inclusive) in YYYYMMDD format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// Optional last day of the date range (inclusive) in YYYYMMDD format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+pub trait Stats {
+    fn get_active_users(&self) -> RequestBuilder;
+
+    fn get_daily_stats(&self, request: GetDailyStatsRequestParameters) -> RequestBuilder;
+}
+
+impl Stats for Api {
+    fn get_active_users(&self) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/stats/active-users");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url))
+    }
+
+    fn get_daily_stats(&self, request: GetDailyStatsRequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/stats/daily");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_daily_stats_build_request() {
+        let management = init_api().await;
+        let parameters = GetDailyStatsRequestParameters {
+            from: Some(String::from("20201001")),
+            to: Some(String::from("20201031")),
+        };
+        let request = management.get_daily_stats(parameters).build().unwrap();
+        let test_url = format!(
+            "{}/api/v2/stats/daily?from=20201001&to=20201031",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::GET);
+        assert_eq!(request.url().as_str(), test_url);
+    }
+
+    #[test]
+    fn deserialize_daily_stats() {
+        let json = r#"[{
+  "date": "2020-10-01T00:00:00.000Z",
+  "logins": 100,
+  "signups": 100,
+  "leaked_passwords": 100,
+  "updated_at": "2020-10-01T23:59:59.000Z",
+  "created_at": "2020-10-01T00:00:00.000Z"
+}]"#;
+        let stats = serde_json::from_str::<Vec<DailyStats>>(json).unwrap();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].logins, 100);
+    }
+}
@@ -0,0 +1,185 @@
+use super::Api;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceFlowCharset {
+    Base20,
+    Digits,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeviceFlow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charset: Option<DeviceFlowCharset>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ErrorPage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_log_link: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TenantSettings {
+    pub friendly_name: Option<String>,
+    pub picture_url: Option<String>,
+    pub support_email: Option<String>,
+    pub support_url: Option<String>,
+    pub default_audience: Option<String>,
+    pub default_directory: Option<String>,
+    pub default_redirection_uri: Option<String>,
+    pub allowed_logout_urls: Option<Vec<String>>,
+    pub session_lifetime: Option<f64>,
+    pub idle_session_lifetime: Option<f64>,
+    pub enabled_locales: Option<Vec<String>>,
+    pub error_page: Option<ErrorPage>,
+    pub device_flow: Option<DeviceFlow>,
+    pub flags: Option<HashMap<String, bool>>,
+    pub sandbox_version: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct GetTenantSettingsRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_fields: Option<bool>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct UpdateTenantSettingsRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub picture_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_audience: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_directory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_redirection_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_logout_urls: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_lifetime: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_session_lifetime: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_locales: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_page: Option<ErrorPage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_flow: Option<DeviceFlow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<HashMap<String, bool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sandbox_version: Option<String>,
+}
+
+pub trait Tenants {
+    fn get_tenant_settings(&self, request: GetTenantSettingsRequestParameters) -> RequestBuilder;
+
+    fn update_tenant_settings(
+        &self,
+        request: UpdateTenantSettingsRequestParameters,
+    ) -> RequestBuilder;
+}
+
+impl Tenants for Api {
+    fn get_tenant_settings(&self, request: GetTenantSettingsRequestParameters) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/tenants/settings");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+
+    fn update_tenant_settings(
+        &self,
+        request: UpdateTenantSettingsRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = String::from("/api/v2/tenants/settings");
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.patch(url)).json(&request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_tenant_settings_build_request() {
+        let management = init_api().await;
+        let parameters = GetTenantSettingsRequestParameters {
+            fields: Some(String::from("default_audience")),
+            include_fields: None,
+        };
+        let request = management.get_tenant_settings(parameters).build().unwrap();
+        let test_url = format!(
+            "{}/api/v2/tenants/settings?fields=default_audience",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::GET);
+        assert_eq!(request.url().as_str(), test_url);
+        assert!(request.body().is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn update_tenant_settings_build_request() {
+        let management = init_api().await;
+        let parameters = UpdateTenantSettingsRequestParameters {
+            friendly_name: Some(String::from("some_awesome_tenant")),
+            session_lifetime: Some(168.0),
+            device_flow: Some(DeviceFlow {
+                charset: Some(DeviceFlowCharset::Digits),
+                mask: None,
+            }),
+            ..Default::default()
+        };
+        let request = management
+            .update_tenant_settings(parameters)
+            .build()
+            .unwrap();
+        let test_url = format!("{}/api/v2/tenants/settings", mockito::server_url());
+        let test_body = String::from(
+            "{\"friendly_name\":\"some_awesome_tenant\",\
+            \"session_lifetime\":168.0,\
+            \"device_flow\":{\"charset\":\"digits\"}}",
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::PATCH);
+        assert_eq!(request.url().as_str(), test_url);
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            test_body.as_bytes(),
+        );
+    }
+
+    #[test]
+    fn deserialize_tenant_settings() {
+        let json = r#"{
+  "friendly_name": "My Company",
+  "default_audience": "https://api.example.com",
+  "flags": {"enable_client_connections": false},
+  "enabled_locales": ["en", "fr"]
+}"#;
+        let settings = serde_json::from_str::<TenantSettings>(json).unwrap();
+        assert_eq!(
+            settings.default_audience,
+            Some(String::from("https://api.example.com"))
+        );
+        assert!(!settings.flags.unwrap()["enable_client_connections"]);
+    }
+}
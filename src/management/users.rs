@@ -7,12 +7,73 @@ pub struct AssignRolesToUserRequestParameters {
     pub roles: Vec<String>,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct CheckpointPaginationRequestParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take: Option<u16>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    pub id: String,
+    pub user_id: String,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub authenticated_at: Option<String>,
+    pub last_interacted_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub idle_expires_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sessions {
+    pub sessions: Vec<Session>,
+    pub next: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshToken {
+    pub id: String,
+    pub user_id: String,
+    pub client_id: Option<String>,
+    pub session_id: Option<String>,
+    pub rotating: Option<bool>,
+    pub created_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub idle_expires_at: Option<String>,
+    pub last_exchanged_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshTokens {
+    pub tokens: Vec<RefreshToken>,
+    pub next: Option<String>,
+}
+
 pub trait Users {
     fn assign_roles_to_user(
         &self,
         id: String,
         request: AssignRolesToUserRequestParameters,
     ) -> RequestBuilder;
+
+    fn list_user_sessions(
+        &self,
+        id: String,
+        request: CheckpointPaginationRequestParameters,
+    ) -> RequestBuilder;
+
+    fn delete_user_sessions(&self, id: String) -> RequestBuilder;
+
+    fn list_user_refresh_tokens(
+        &self,
+        id: String,
+        request: CheckpointPaginationRequestParameters,
+    ) -> RequestBuilder;
+
+    fn delete_user_refresh_tokens(&self, id: String) -> RequestBuilder;
 }
 
 impl Users for Api {
@@ -25,4 +86,77 @@ impl Users for Api {
         let url = self.base_url.join(&endpoint).unwrap();
         self.apply_auth(self.client.post(url).json(&request))
     }
+
+    fn list_user_sessions(
+        &self,
+        id: String,
+        request: CheckpointPaginationRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/users/{}/sessions", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+
+    fn delete_user_sessions(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/users/{}/sessions", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+
+    fn list_user_refresh_tokens(
+        &self,
+        id: String,
+        request: CheckpointPaginationRequestParameters,
+    ) -> RequestBuilder {
+        let endpoint = format!("/api/v2/users/{}/refresh-tokens", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.get(url)).query(&request)
+    }
+
+    fn delete_user_refresh_tokens(&self, id: String) -> RequestBuilder {
+        let endpoint = format!("/api/v2/users/{}/refresh-tokens", id);
+        let url = self.base_url.join(&endpoint).unwrap();
+        self.apply_auth(self.client.delete(url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::tests::init_api;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn list_user_sessions_build_request() {
+        let management = init_api().await;
+        let parameters = CheckpointPaginationRequestParameters {
+            from: None,
+            take: Some(25),
+        };
+        let request = management
+            .list_user_sessions(String::from("some_awesome_user"), parameters)
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/users/some_awesome_user/sessions?take=25",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::GET);
+        assert_eq!(request.url().as_str(), test_url);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn delete_user_refresh_tokens_build_request() {
+        let management = init_api().await;
+        let request = management
+            .delete_user_refresh_tokens(String::from("some_awesome_user"))
+            .build()
+            .unwrap();
+        let test_url = format!(
+            "{}/api/v2/users/some_awesome_user/refresh-tokens",
+            mockito::server_url()
+        );
+        assert_eq!(request.method().as_str(), reqwest::Method::DELETE);
+        assert_eq!(request.url().as_str(), test_url);
+        assert!(request.body().is_none());
+    }
 }
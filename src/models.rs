@@ -1,4 +1,7 @@
-use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -14,7 +17,257 @@ pub struct Role {
     pub description: String,
 }
 
+/// Standard OpenID Connect claims returned by `/userinfo`. Custom claims, which Auth0 requires to
+/// be namespaced (e.g. `https://example.com/roles`), are collected in `other`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UserInfo {
+    pub sub: String,
+    pub name: Option<String>,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    pub middle_name: Option<String>,
+    pub nickname: Option<String>,
+    pub preferred_username: Option<String>,
+    pub profile: Option<String>,
+    pub picture: Option<String>,
+    pub website: Option<String>,
+    pub email: Option<String>,
+    pub email_verified: Option<bool>,
+    pub gender: Option<String>,
+    pub birthdate: Option<String>,
+    pub zoneinfo: Option<String>,
+    pub locale: Option<String>,
+    pub phone_number: Option<String>,
+    pub phone_number_verified: Option<bool>,
+    pub address: Option<serde_json::Value>,
+    pub updated_at: Option<String>,
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+impl UserInfo {
+    /// Deserializes a custom claim, e.g. `user_info.claim::<Vec<String>>("https://example.com/roles")`.
+    pub fn claim<T: serde::de::DeserializeOwned>(&self, name: &str) -> Option<T> {
+        self.other
+            .get(name)
+            .and_then(|claim| serde_json::from_value(claim.clone()).ok())
+    }
+}
+
+/// `grant_type` of a token request. Endpoints fill it in, so it never has to be spelled out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GrantType {
+    #[serde(rename = "authorization_code")]
+    AuthorizationCode,
+    #[serde(rename = "client_credentials")]
+    ClientCredentials,
+    #[serde(rename = "password")]
+    Password,
+    #[serde(rename = "http://auth0.com/oauth/grant-type/password-realm")]
+    PasswordRealm,
+    #[serde(rename = "refresh_token")]
+    RefreshToken,
+    #[serde(rename = "urn:ietf:params:oauth:grant-type:device_code")]
+    DeviceCode,
+    #[serde(rename = "urn:ietf:params:oauth:grant-type:token-exchange")]
+    TokenExchange,
+    #[serde(rename = "http://auth0.com/oauth/grant-type/mfa-otp")]
+    MfaOtp,
+    #[serde(rename = "http://auth0.com/oauth/grant-type/mfa-oob")]
+    MfaOob,
+    #[serde(rename = "http://auth0.com/oauth/grant-type/mfa-recovery-code")]
+    MfaRecoveryCode,
+    #[serde(rename = "http://auth0.com/oauth/grant-type/passwordless/otp")]
+    PasswordlessOtp,
+    #[serde(rename = "urn:openid:params:grant-type:ciba")]
+    Ciba,
+}
+
+impl GrantType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GrantType::AuthorizationCode => "authorization_code",
+            GrantType::ClientCredentials => "client_credentials",
+            GrantType::Password => "password",
+            GrantType::PasswordRealm => "http://auth0.com/oauth/grant-type/password-realm",
+            GrantType::RefreshToken => "refresh_token",
+            GrantType::DeviceCode => "urn:ietf:params:oauth:grant-type:device_code",
+            GrantType::TokenExchange => "urn:ietf:params:oauth:grant-type:token-exchange",
+            GrantType::MfaOtp => "http://auth0.com/oauth/grant-type/mfa-otp",
+            GrantType::MfaOob => "http://auth0.com/oauth/grant-type/mfa-oob",
+            GrantType::MfaRecoveryCode => "http://auth0.com/oauth/grant-type/mfa-recovery-code",
+            GrantType::PasswordlessOtp => "http://auth0.com/oauth/grant-type/passwordless/otp",
+            GrantType::Ciba => "urn:openid:params:grant-type:ciba",
+        }
+    }
+}
+
+impl fmt::Display for GrantType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `response_type` of an `/authorize` request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResponseType {
+    #[serde(rename = "code")]
+    Code,
+    #[serde(rename = "token")]
+    Token,
+    #[serde(rename = "id_token")]
+    IdToken,
+    #[serde(rename = "id_token token")]
+    IdTokenToken,
+    #[serde(rename = "code id_token")]
+    CodeIdToken,
+    #[serde(rename = "code token")]
+    CodeToken,
+    #[serde(rename = "code id_token token")]
+    CodeIdTokenToken,
+}
+
+impl ResponseType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResponseType::Code => "code",
+            ResponseType::Token => "token",
+            ResponseType::IdToken => "id_token",
+            ResponseType::IdTokenToken => "id_token token",
+            ResponseType::CodeIdToken => "code id_token",
+            ResponseType::CodeToken => "code token",
+            ResponseType::CodeIdTokenToken => "code id_token token",
+        }
+    }
+}
+
+impl fmt::Display for ResponseType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChallengeType {
+    Otp,
+    Oob,
+}
+
+impl ChallengeType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChallengeType::Otp => "otp",
+            ChallengeType::Oob => "oob",
+        }
+    }
+}
+
+/// Space separated `challenge_type` list of an MFA challenge request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChallengeTypes(pub Vec<ChallengeType>);
+
+impl Serialize for ChallengeTypes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let types = self.0.iter().map(ChallengeType::as_str).collect::<Vec<_>>();
+        serializer.serialize_str(&types.join(" "))
+    }
+}
+
+impl<'de> Deserialize<'de> for ChallengeTypes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let types = String::deserialize(deserializer)?;
+        types
+            .split_whitespace()
+            .map(|challenge_type| {
+                ChallengeType::deserialize(serde::de::value::StrDeserializer::new(challenge_type))
+            })
+            .collect::<Result<_, _>>()
+            .map(ChallengeTypes)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OobChannel {
+    Sms,
+    Voice,
+    Email,
+    Auth0,
+    /// A channel added to Auth0 after this version; only found in responses, and fails to
+    /// serialize.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuthenticatorType {
+    Otp,
+    Oob,
+    RecoveryCode,
+    /// A type added to Auth0 after this version, e.g. `webauthn-roaming`; only found in
+    /// responses, and fails to serialize.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+/// Token type identifiers of RFC 8693 token exchange. A custom token exchange profile is an
+/// [`TokenType::Other`] URI.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum TokenType {
+    AccessToken,
+    RefreshToken,
+    IdToken,
+    Jwt,
+    Saml1,
+    Saml2,
+    Other(String),
+}
+
+impl TokenType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TokenType::AccessToken => "urn:ietf:params:oauth:token-type:access_token",
+            TokenType::RefreshToken => "urn:ietf:params:oauth:token-type:refresh_token",
+            TokenType::IdToken => "urn:ietf:params:oauth:token-type:id_token",
+            TokenType::Jwt => "urn:ietf:params:oauth:token-type:jwt",
+            TokenType::Saml1 => "urn:ietf:params:oauth:token-type:saml1",
+            TokenType::Saml2 => "urn:ietf:params:oauth:token-type:saml2",
+            TokenType::Other(token_type) => token_type,
+        }
+    }
+}
+
+impl From<String> for TokenType {
+    fn from(token_type: String) -> Self {
+        match token_type.as_str() {
+            "urn:ietf:params:oauth:token-type:access_token" => TokenType::AccessToken,
+            "urn:ietf:params:oauth:token-type:refresh_token" => TokenType::RefreshToken,
+            "urn:ietf:params:oauth:token-type:id_token" => TokenType::IdToken,
+            "urn:ietf:params:oauth:token-type:jwt" => TokenType::Jwt,
+            "urn:ietf:params:oauth:token-type:saml1" => TokenType::Saml1,
+            "urn:ietf:params:oauth:token-type:saml2" => TokenType::Saml2,
+            _ => TokenType::Other(token_type),
+        }
+    }
+}
+
+impl From<TokenType> for String {
+    fn from(token_type: TokenType) -> Self {
+        token_type.as_str().to_string()
+    }
+}
+
+impl fmt::Display for TokenType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     #[test]
     fn test_deserialize_success_auth_response() {
@@ -47,4 +300,47 @@ mod tests {
 
         assert_eq!(response.is_ok(), true);
     }
+
+    #[test]
+    fn test_serialize_challenge_types() {
+        let challenge_types =
+            super::ChallengeTypes(vec![super::ChallengeType::Otp, super::ChallengeType::Oob]);
+        let json = serde_json::to_string(&challenge_types).unwrap();
+        assert_eq!(json, r#""otp oob""#);
+        let response = serde_json::from_str::<super::ChallengeTypes>(&json).unwrap();
+        assert_eq!(response, challenge_types);
+    }
+
+    #[test]
+    fn test_deserialize_token_type() {
+        let json = r#""urn:ietf:params:oauth:token-type:access_token""#;
+        let response = serde_json::from_str::<super::TokenType>(json).unwrap();
+        assert_eq!(response, super::TokenType::AccessToken);
+        let json = r#""urn:some-awesome-legacy-idp""#;
+        let response = serde_json::from_str::<super::TokenType>(json).unwrap();
+        assert_eq!(
+            response,
+            super::TokenType::Other(String::from("urn:some-awesome-legacy-idp"))
+        );
+    }
+
+    #[test]
+    fn test_deserialize_unknown_authenticator() {
+        let json = r#""webauthn-roaming""#;
+        let response = serde_json::from_str::<super::AuthenticatorType>(json).unwrap();
+        assert_eq!(response, super::AuthenticatorType::Unknown);
+        let json = r#""push""#;
+        let response = serde_json::from_str::<super::OobChannel>(json).unwrap();
+        assert_eq!(response, super::OobChannel::Unknown);
+    }
+
+    #[test]
+    fn test_serialize_unknown_authenticator() {
+        assert!(serde_json::to_string(&super::AuthenticatorType::Unknown).is_err());
+        assert!(serde_json::to_string(&super::OobChannel::Unknown).is_err());
+        assert_eq!(
+            serde_json::to_string(&super::AuthenticatorType::RecoveryCode).unwrap(),
+            r#""recovery-code""#
+        );
+    }
 }
@@ -0,0 +1,429 @@
+//! Retry and rate limiting shared by the Authentication and Management API clients.
+//!
+//! Auth0 reports its quota through the `X-RateLimit-Limit`, `X-RateLimit-Remaining` and
+//! `X-RateLimit-Reset` headers and answers with `429 Too Many Requests` once the quota is
+//! exhausted. [`send`] retries such responses according to a [`RetryPolicy`], and an optional
+//! [`RateLimiter`] keeps bulk callers below the quota in the first place.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use rand::Rng;
+use reqwest::{header, Client, Method, RequestBuilder, Response, StatusCode};
+use tokio::sync::Mutex;
+
+const RATE_LIMIT_LIMIT: &str = "x-ratelimit-limit";
+const RATE_LIMIT_REMAINING: &str = "x-ratelimit-remaining";
+const RATE_LIMIT_RESET: &str = "x-ratelimit-reset";
+
+/// Quota information reported by Auth0 on every response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub limit: u64,
+    pub remaining: u64,
+    /// Unix timestamp (in seconds) at which the quota is replenished.
+    pub reset: u64,
+}
+
+impl RateLimit {
+    pub fn from_headers(headers: &header::HeaderMap) -> Option<RateLimit> {
+        Some(RateLimit {
+            limit: header_u64(headers, RATE_LIMIT_LIMIT)?,
+            remaining: header_u64(headers, RATE_LIMIT_REMAINING)?,
+            reset: header_u64(headers, RATE_LIMIT_RESET)?,
+        })
+    }
+}
+
+fn header_u64(headers: &header::HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    /// Randomize backoff delays ("full jitter") so concurrent callers don't retry in lockstep.
+    pub jitter: bool,
+    /// Also retry `429` responses to non-idempotent methods such as `POST`.
+    ///
+    /// Off by default: every Authentication API call is a `POST`, and replaying
+    /// `/passwordless/start` or `/bc-authorize` can send the user a second code or prompt.
+    pub retry_throttled_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retry_throttled_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Whether a response may be retried.
+    ///
+    /// Only idempotent methods are retried, unless
+    /// [`retry_throttled_non_idempotent`](RetryPolicy::retry_throttled_non_idempotent) opts other
+    /// methods in for `429`s.
+    pub fn is_retryable(&self, method: &Method, status: StatusCode) -> bool {
+        match status {
+            StatusCode::TOO_MANY_REQUESTS => {
+                is_idempotent(method) || self.retry_throttled_non_idempotent
+            }
+            StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT => is_idempotent(method),
+            _ => false,
+        }
+    }
+
+    /// Delay before the next attempt, where `attempt` is the number of attempts made so far.
+    ///
+    /// `Retry-After`, in seconds or as an HTTP-date, takes precedence; a `429` then waits for
+    /// `X-RateLimit-Reset`. Exponential backoff is used otherwise, since the quota headers Auth0
+    /// sends on gateway errors say nothing about when the gateway recovers.
+    pub fn delay(&self, attempt: u32, status: StatusCode, headers: &header::HeaderMap) -> Duration {
+        if let Some(retry_after) = retry_after(headers) {
+            return retry_after.min(self.max_delay);
+        }
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            if let Some(reset) = header_u64(headers, RATE_LIMIT_RESET) {
+                return until_reset(reset).min(self.max_delay);
+            }
+        }
+        self.backoff(attempt)
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        if self.jitter {
+            delay.mul_f64(rand::thread_rng().gen_range(0.0..=1.0))
+        } else {
+            delay
+        }
+    }
+}
+
+fn retry_after(headers: &header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+/// Time left until `reset`, a Unix timestamp in seconds.
+fn until_reset(reset: u64) -> Duration {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    Duration::from_secs(reset.saturating_sub(now))
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+    )
+}
+
+/// Client-side token bucket, refilled continuously at `requests_per_second`.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    capacity: f64,
+    refill_per_second: f64,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+    /// Set when the server reports that no quota is left: no token is handed out before then.
+    not_before: Option<Instant>,
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_second: u32, burst: u32) -> Self {
+        let capacity = f64::from(burst.max(1));
+        RateLimiter {
+            capacity,
+            refill_per_second: f64::from(requests_per_second.max(1)),
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: capacity,
+                last_refill: Instant::now(),
+                not_before: None,
+            })),
+        }
+    }
+
+    /// Waits until a request may be sent and takes a token from the bucket.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+                let now = Instant::now();
+                match bucket.not_before {
+                    Some(not_before) if now < not_before => not_before - now,
+                    _ => {
+                        bucket.not_before = None;
+                        bucket.tokens = (bucket.tokens
+                            + now.duration_since(bucket.last_refill).as_secs_f64()
+                                * self.refill_per_second)
+                            .min(self.capacity);
+                        bucket.last_refill = now;
+                        if bucket.tokens >= 1.0 {
+                            bucket.tokens -= 1.0;
+                            return;
+                        }
+                        Duration::from_secs_f64((1.0 - bucket.tokens) / self.refill_per_second)
+                    }
+                }
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Holds the bucket empty until the quota is replenished when the server reports that none
+    /// is left, so the next requests wait instead of being throttled.
+    async fn observe(&self, rate_limit: RateLimit) {
+        if rate_limit.remaining == 0 {
+            let mut bucket = self.bucket.lock().await;
+            let reset = Instant::now() + until_reset(rate_limit.reset);
+            bucket.tokens = bucket.tokens.min(0.0);
+            bucket.not_before = Some(
+                bucket
+                    .not_before
+                    .map_or(reset, |not_before| not_before.max(reset)),
+            );
+        }
+    }
+}
+
+async fn observe(limiter: Option<&RateLimiter>, response: &Response) {
+    if let (Some(limiter), Some(rate_limit)) =
+        (limiter, RateLimit::from_headers(response.headers()))
+    {
+        limiter.observe(rate_limit).await;
+    }
+}
+
+/// Sends a request, retrying throttled and transient failures according to `policy`.
+///
+/// Requests whose body can't be cloned (streams) are sent once.
+pub(crate) async fn send(
+    client: &Client,
+    request: RequestBuilder,
+    policy: &RetryPolicy,
+    limiter: Option<&RateLimiter>,
+) -> Result<Response, reqwest::Error> {
+    let request = request.build()?;
+    let mut attempt = 1;
+    loop {
+        if let Some(limiter) = limiter {
+            limiter.acquire().await;
+        }
+        let retry = match request.try_clone() {
+            Some(retry) if attempt < policy.max_attempts => retry,
+            _ => {
+                let response = client.execute(request).await?;
+                observe(limiter, &response).await;
+                return Ok(response);
+            }
+        };
+        let response = client.execute(retry).await?;
+        observe(limiter, &response).await;
+        if !policy.is_retryable(request.method(), response.status()) {
+            return Ok(response);
+        }
+        let delay = policy.delay(attempt, response.status(), response.headers());
+        tracing::warn!(
+            "{} {} returned {}, retrying in {:?} (attempt {}/{})",
+            request.method(),
+            request.url(),
+            response.status(),
+            delay,
+            attempt + 1,
+            policy.max_attempts
+        );
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limit_from_headers() {
+        let mut headers = header::HeaderMap::new();
+        headers.insert(RATE_LIMIT_LIMIT, header::HeaderValue::from_static("50"));
+        headers.insert(RATE_LIMIT_REMAINING, header::HeaderValue::from_static("0"));
+        headers.insert(
+            RATE_LIMIT_RESET,
+            header::HeaderValue::from_static("1700000000"),
+        );
+        let rate_limit = RateLimit::from_headers(&headers).unwrap();
+        assert_eq!(rate_limit.limit, 50);
+        assert_eq!(rate_limit.remaining, 0);
+        assert_eq!(rate_limit.reset, 1700000000);
+    }
+
+    #[test]
+    fn retry_policy_delay() {
+        let policy = RetryPolicy {
+            jitter: false,
+            ..Default::default()
+        };
+        let throttled = StatusCode::TOO_MANY_REQUESTS;
+        let unavailable = StatusCode::SERVICE_UNAVAILABLE;
+        let mut headers = header::HeaderMap::new();
+        assert_eq!(
+            policy.delay(1, throttled, &headers),
+            Duration::from_millis(500)
+        );
+        assert_eq!(policy.delay(3, throttled, &headers), Duration::from_secs(2));
+        assert_eq!(
+            policy.delay(30, throttled, &headers),
+            Duration::from_secs(30)
+        );
+
+        headers.insert(RATE_LIMIT_RESET, header::HeaderValue::from_static("0"));
+        assert_eq!(policy.delay(1, throttled, &headers), Duration::from_secs(0));
+        assert_eq!(
+            policy.delay(1, unavailable, &headers),
+            Duration::from_millis(500)
+        );
+
+        headers.insert(header::RETRY_AFTER, header::HeaderValue::from_static("7"));
+        assert_eq!(policy.delay(1, throttled, &headers), Duration::from_secs(7));
+        assert_eq!(
+            policy.delay(1, unavailable, &headers),
+            Duration::from_secs(7)
+        );
+    }
+
+    #[test]
+    fn retry_policy_is_retryable() {
+        let policy = RetryPolicy::default();
+        assert!(policy.is_retryable(&Method::GET, StatusCode::TOO_MANY_REQUESTS));
+        assert!(!policy.is_retryable(&Method::POST, StatusCode::TOO_MANY_REQUESTS));
+        assert!(policy.is_retryable(&Method::GET, StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!policy.is_retryable(&Method::POST, StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!policy.is_retryable(&Method::GET, StatusCode::BAD_REQUEST));
+
+        let policy = RetryPolicy {
+            retry_throttled_non_idempotent: true,
+            ..Default::default()
+        };
+        assert!(policy.is_retryable(&Method::POST, StatusCode::TOO_MANY_REQUESTS));
+        assert!(!policy.is_retryable(&Method::POST, StatusCode::SERVICE_UNAVAILABLE));
+    }
+
+    #[test]
+    fn retry_policy_delay_http_date() {
+        let policy = RetryPolicy {
+            jitter: false,
+            ..Default::default()
+        };
+        let mut headers = header::HeaderMap::new();
+        let retry_after = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(10));
+        headers.insert(
+            header::RETRY_AFTER,
+            header::HeaderValue::from_str(&retry_after).unwrap(),
+        );
+        let delay = policy.delay(1, StatusCode::TOO_MANY_REQUESTS, &headers);
+        assert!(delay > Duration::from_secs(8) && delay <= Duration::from_secs(10));
+
+        headers.insert(
+            header::RETRY_AFTER,
+            header::HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(
+            policy.delay(1, StatusCode::TOO_MANY_REQUESTS, &headers),
+            Duration::from_secs(0)
+        );
+    }
+
+    #[tokio::test]
+    async fn rate_limiter_acquire() {
+        let limiter = RateLimiter::new(20, 2);
+        let start = Instant::now();
+        for _ in 0..4 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[tokio::test]
+    async fn rate_limiter_waits_for_reset() {
+        let limiter = RateLimiter::new(20, 2);
+        let reset = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 1;
+        limiter
+            .observe(RateLimit {
+                limit: 50,
+                remaining: 0,
+                reset,
+            })
+            .await;
+        let start = Instant::now();
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(900));
+    }
+
+    #[tokio::test]
+    async fn send_observes_last_response() {
+        let reset = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 2;
+        let _mock = mockito::mock("GET", "/some_awesome_throttled_resource")
+            .with_status(429)
+            .with_header("retry-after", "0")
+            .with_header(RATE_LIMIT_LIMIT, "50")
+            .with_header(RATE_LIMIT_REMAINING, "0")
+            .with_header(RATE_LIMIT_RESET, &reset.to_string())
+            .create();
+        let client = Client::new();
+        let limiter = RateLimiter::new(20, 2);
+        let url = format!("{}/some_awesome_throttled_resource", mockito::server_url());
+        let response = send(
+            &client,
+            client.get(url),
+            &RetryPolicy::none(),
+            Some(&limiter),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        let start = Instant::now();
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(900));
+    }
+}
@@ -1,5 +1,6 @@
 use auth0::authentication::authorize_application::*;
 use auth0::authentication::*;
+use auth0::models::ResponseType;
 use mockito::{mock, Matcher};
 
 #[tokio::test]
@@ -8,7 +9,7 @@ async fn authorization_code_flow_send_request() {
         .match_query(Matcher::AllOf(vec![
             Matcher::UrlEncoded("audience".into(), "some_awesome_audience".into()),
             Matcher::UrlEncoded("scope".into(), "some_awesome_scope".into()),
-            Matcher::UrlEncoded("response_type".into(), "some_awesome_response_type".into()),
+            Matcher::UrlEncoded("response_type".into(), "code".into()),
             Matcher::UrlEncoded("client_id".into(), "some_awesome_client_id".into()),
             Matcher::UrlEncoded("state".into(), "some_awesome_state".into()),
         ]))
@@ -19,7 +20,7 @@ async fn authorization_code_flow_send_request() {
     let test_parameters = authorize_application::authorization_code_flow::RequestParameters {
         audience: Some(String::from("some_awesome_audience")),
         scope: Some(String::from("some_awesome_scope")),
-        response_type: String::from("some_awesome_response_type"),
+        response_type: ResponseType::Code,
         client_id: String::from("some_awesome_client_id"),
         state: Some(String::from("some_awesome_state")),
         redirect_uri: None,
@@ -41,7 +42,7 @@ async fn authorization_code_flow_with_pkce_send_request() {
         .match_query(Matcher::AllOf(vec![
             Matcher::UrlEncoded("audience".into(), "some_awesome_audience".into()),
             Matcher::UrlEncoded("scope".into(), "some_awesome_scope".into()),
-            Matcher::UrlEncoded("response_type".into(), "some_awesome_response_type".into()),
+            Matcher::UrlEncoded("response_type".into(), "code".into()),
             Matcher::UrlEncoded("client_id".into(), "some_awesome_client_id".into()),
             Matcher::UrlEncoded("state".into(), "some_awesome_state".into()),
             Matcher::UrlEncoded(
@@ -60,7 +61,7 @@ async fn authorization_code_flow_with_pkce_send_request() {
     let test_parameters = authorize_application::pkce::RequestParameters {
         audience: Some(String::from("some_awesome_audience")),
         scope: Some(String::from("some_awesome_scope")),
-        response_type: String::from("some_awesome_response_type"),
+        response_type: ResponseType::Code,
         client_id: String::from("some_awesome_client_id"),
         state: Some(String::from("some_awesome_state")),
         redirect_uri: None,
@@ -84,9 +85,10 @@ async fn implicit_flow_send_request() {
         .match_query(Matcher::AllOf(vec![
             Matcher::UrlEncoded("audience".into(), "some_awesome_audience".into()),
             Matcher::UrlEncoded("scope".into(), "some_awesome_scope".into()),
-            Matcher::UrlEncoded("response_type".into(), "some_awesome_response_type".into()),
+            Matcher::UrlEncoded("response_type".into(), "id_token token".into()),
             Matcher::UrlEncoded("client_id".into(), "some_awesome_client_id".into()),
             Matcher::UrlEncoded("state".into(), "some_awesome_state".into()),
+            Matcher::UrlEncoded("nonce".into(), "some_awesome_nonce".into()),
         ]))
         .create();
     let base_url = reqwest::Url::parse(&mockito::server_url()).unwrap();
@@ -95,11 +97,11 @@ async fn implicit_flow_send_request() {
     let test_parameters = authorize_application::implicit_flow::RequestParameters {
         audience: Some(String::from("some_awesome_audience")),
         scope: Some(String::from("some_awesome_scope")),
-        response_type: String::from("some_awesome_response_type"),
+        response_type: ResponseType::IdTokenToken,
         client_id: String::from("some_awesome_client_id"),
         state: Some(String::from("some_awesome_state")),
         redirect_uri: None,
-        nonce: None,
+        nonce: Some(String::from("some_awesome_nonce")),
         connection: None,
         prompt: None,
     };
@@ -0,0 +1,38 @@
+use auth0::authentication::backchannel_logout::*;
+use auth0::authentication::*;
+use auth0::error::LogoutTokenError;
+use mockito::mock;
+
+#[tokio::test]
+async fn logout_token_validator_fetch_send_request() {
+    let mock = mock("GET", "/.well-known/jwks.json")
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"keys":[{"kty":"RSA",
+            "use":"sig",
+            "alg":"RS256",
+            "kid":"some_awesome_key_id",
+            "n":"1C3r04rjspfjV4jUYVFpSeT8HCTdyae_dVfJ07ihiyyGaTCQnligdQ",
+            "e":"AQAB"}]}"#,
+        )
+        .expect(2)
+        .create();
+    let base_url = reqwest::Url::parse(&mockito::server_url()).unwrap();
+    let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+    let backchannel_logout = Api::init(base_url, authentication);
+    let validator =
+        LogoutTokenValidator::fetch(&backchannel_logout, String::from("some_awesome_client_id"))
+            .await
+            .unwrap();
+    // {"alg":"RS256","kid":"some_other_key_id"}.{}.signature
+    let logout_token = "eyJhbGciOiJSUzI1NiIsImtpZCI6InNvbWVfb3RoZXJfa2V5X2lkIn0.e30.c2lnbmF0dXJl";
+    match validator.validate(logout_token).await {
+        Err(LogoutTokenError::UnknownKey(kid)) => {
+            assert_eq!(kid.as_deref(), Some("some_other_key_id"));
+        }
+        _ => panic!("expected an unknown key"),
+    }
+    // The unknown key made the validator fetch the keys once more.
+    mock.assert();
+    assert!(mock.matched());
+}
@@ -0,0 +1,132 @@
+use auth0::authentication::ciba::*;
+use auth0::authentication::*;
+use auth0::error::{AuthenticationError, Error};
+use mockito::mock;
+
+fn ciba_api() -> Api {
+    let base_url = reqwest::Url::parse(&mockito::server_url()).unwrap();
+    let authentication = AuthenticationMethod::ClientIDClientSecret(
+        String::from("some_awesome_client_id"),
+        String::from("some_awesome_client_secret"),
+    );
+    Api::init(base_url, authentication)
+}
+
+#[tokio::test]
+async fn backchannel_authorize_send_request() {
+    let mock = mock("POST", "/bc-authorize")
+        .match_header("content-type", "application/x-www-form-urlencoded")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded(
+                String::from("client_secret"),
+                String::from("some_awesome_client_secret"),
+            ),
+            mockito::Matcher::UrlEncoded(
+                String::from("login_hint"),
+                String::from(
+                    r#"{"format":"iss_sub","iss":"https://YOUR_DOMAIN/","sub":"some_awesome_user_id"}"#,
+                ),
+            ),
+        ]))
+        .with_body(
+            r#"{"auth_req_id":"some_awesome_auth_req_id",
+            "expires_in":300,
+            "interval":5}"#,
+        )
+        .create();
+    let ciba = ciba_api();
+    let test_parameters = backchannel_authorize::RequestParameters {
+        client_id: None,
+        client_secret: None,
+        login_hint: backchannel_authorize::LoginHint::iss_sub(
+            String::from("https://YOUR_DOMAIN/"),
+            String::from("some_awesome_user_id"),
+        ),
+        scope: String::from("openid"),
+        binding_message: String::from("some_awesome_binding_message"),
+        audience: None,
+        requested_expiry: None,
+    };
+    let test_response = ciba
+        .send(ciba.backchannel_authorize(test_parameters))
+        .await
+        .unwrap()
+        .json::<backchannel_authorize::Response>()
+        .await
+        .unwrap();
+    mock.assert();
+    assert_eq!(test_response.auth_req_id, "some_awesome_auth_req_id");
+    assert_eq!(test_response.interval, Some(5));
+}
+
+#[tokio::test]
+async fn poll_ciba_send_request() {
+    let pending = mock("POST", "/oauth/token")
+        .match_body(mockito::Matcher::UrlEncoded(
+            String::from("auth_req_id"),
+            String::from("some_awesome_auth_req_id"),
+        ))
+        .with_status(400)
+        .with_body(
+            r#"{"error":"authorization_pending",
+            "error_description":"The end-user authorization is pending"}"#,
+        )
+        .expect(1)
+        .create();
+    let token = mock("POST", "/oauth/token")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded(
+                String::from("grant_type"),
+                String::from("urn:openid:params:grant-type:ciba"),
+            ),
+            mockito::Matcher::UrlEncoded(
+                String::from("auth_req_id"),
+                String::from("some_awesome_auth_req_id"),
+            ),
+        ]))
+        .with_body(
+            r#"{"access_token":"some_awesome_access_token",
+            "expires_in":86400,
+            "token_type":"Bearer"}"#,
+        )
+        .create();
+    let ciba = ciba_api();
+    let authorization = backchannel_authorize::Response {
+        auth_req_id: String::from("some_awesome_auth_req_id"),
+        expires_in: 300,
+        interval: Some(0),
+    };
+    let test_response = ciba.poll_ciba(&authorization).await.unwrap();
+    pending.assert();
+    token.assert();
+    assert_eq!(test_response.access_token, "some_awesome_access_token");
+}
+
+#[tokio::test]
+async fn poll_ciba_access_denied_send_request() {
+    let denied = mock("POST", "/oauth/token")
+        .match_body(mockito::Matcher::UrlEncoded(
+            String::from("auth_req_id"),
+            String::from("some_denied_auth_req_id"),
+        ))
+        .with_status(400)
+        .with_body(
+            r#"{"error":"access_denied",
+            "error_description":"The end-user denied the authorization request"}"#,
+        )
+        .create();
+    let ciba = ciba_api();
+    let authorization = backchannel_authorize::Response {
+        auth_req_id: String::from("some_denied_auth_req_id"),
+        expires_in: 300,
+        interval: Some(0),
+    };
+    let test_response = ciba.poll_ciba(&authorization).await;
+    denied.assert();
+    assert!(matches!(
+        test_response,
+        Err(Error::AuthenticationError(
+            AuthenticationError::AccessDenied(_)
+        ))
+    ));
+}
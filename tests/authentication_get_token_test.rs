@@ -1,5 +1,6 @@
 use auth0::authentication::get_token::*;
 use auth0::authentication::*;
+use auth0::models::TokenType;
 use mockito::mock;
 
 #[tokio::test]
@@ -7,7 +8,7 @@ async fn authorization_code_flow_send_request() {
     let mock = mock("POST", "/oauth/token")
         .match_header("content-type", "application/x-www-form-urlencoded")
         .match_body(
-            "grant_type=some_awesome_grant&\
+            "grant_type=authorization_code&\
             client_id=some_awesome_client_id&\
             client_secret=some_awesome_client_secret&\
             code=some_awesome_code",
@@ -17,9 +18,8 @@ async fn authorization_code_flow_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let get_token = Api::init(base_url, authentication);
     let test_parameters = get_token::authorization_code_flow::RequestParameters {
-        grant_type: String::from("some_awesome_grant"),
-        client_id: String::from("some_awesome_client_id"),
-        client_secret: String::from("some_awesome_client_secret"),
+        client_id: Some(String::from("some_awesome_client_id")),
+        client_secret: Some(String::from("some_awesome_client_secret")),
         code: String::from("some_awesome_code"),
         redirect_uri: None,
     };
@@ -37,7 +37,7 @@ async fn authorization_code_flow_with_pkce_send_request() {
     let mock = mock("POST", "/oauth/token")
         .match_header("content-type", "application/x-www-form-urlencoded")
         .match_body(
-            "grant_type=some_awesome_grant&\
+            "grant_type=authorization_code&\
             client_id=some_awesome_client_id&\
             code=some_awesome_code&\
             code_verifier=some_awesome_code_verifier",
@@ -47,8 +47,7 @@ async fn authorization_code_flow_with_pkce_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let get_token = Api::init(base_url, authentication);
     let test_parameters = get_token::authorization_code_flow_with_pkce::RequestParameters {
-        grant_type: String::from("some_awesome_grant"),
-        client_id: String::from("some_awesome_client_id"),
+        client_id: Some(String::from("some_awesome_client_id")),
         code: String::from("some_awesome_code"),
         code_verifier: String::from("some_awesome_code_verifier"),
         redirect_uri: None,
@@ -67,7 +66,7 @@ async fn client_credentials_flow_send_request() {
     let mock = mock("POST", "/oauth/token")
         .match_header("content-type", "application/x-www-form-urlencoded")
         .match_body(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=client_credentials&\
             client_id=some_awesome_client_id&\
             client_secret=some_awesome_client_secret&\
             audience=some_awesome_audience_api",
@@ -77,9 +76,8 @@ async fn client_credentials_flow_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let get_token = Api::init(base_url, authentication);
     let test_parameters = get_token::client_credentials_flow::RequestParameters {
-        grant_type: String::from("some_awesome_grant_type"),
-        client_id: String::from("some_awesome_client_id"),
-        client_secret: String::from("some_awesome_client_secret"),
+        client_id: Some(String::from("some_awesome_client_id")),
+        client_secret: Some(String::from("some_awesome_client_secret")),
         audience: String::from("some_awesome_audience_api"),
     };
     let test_response = get_token
@@ -96,7 +94,7 @@ async fn resource_owner_password_send_request() {
     let mock = mock("POST", "/oauth/token")
         .match_header("content-type", "application/x-www-form-urlencoded")
         .match_body(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=password&\
             client_id=some_awesome_client_id&\
             username=some_awesome_username&\
             password=some_awesome_password&\
@@ -107,8 +105,7 @@ async fn resource_owner_password_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let get_token = Api::init(base_url, authentication);
     let test_parameters = get_token::resource_owner_password::RequestParameters {
-        grant_type: String::from("some_awesome_grant_type"),
-        client_id: String::from("some_awesome_client_id"),
+        client_id: Some(String::from("some_awesome_client_id")),
         client_secret: None,
         audience: None,
         username: String::from("some_awesome_username"),
@@ -131,7 +128,7 @@ async fn device_authorization_flow_send_request() {
     let mock = mock("POST", "/oauth/token")
         .match_header("content-type", "application/x-www-form-urlencoded")
         .match_body(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Adevice_code&\
             client_id=some_awesome_client_id&\
             device_code=some_awesome_device_code",
         )
@@ -140,8 +137,7 @@ async fn device_authorization_flow_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let get_token = Api::init(base_url, authentication);
     let test_parameters = get_token::device_authorization_flow::RequestParameters {
-        grant_type: String::from("some_awesome_grant_type"),
-        client_id: String::from("some_awesome_client_id"),
+        client_id: Some(String::from("some_awesome_client_id")),
         device_code: String::from("some_awesome_device_code"),
     };
     let test_response = get_token
@@ -158,7 +154,7 @@ async fn refresh_token_send_request() {
     let mock = mock("POST", "/oauth/token")
         .match_header("content-type", "application/x-www-form-urlencoded")
         .match_body(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=refresh_token&\
             client_id=some_awesome_client_id&\
             refresh_token=some_awesome_refresh_token",
         )
@@ -167,8 +163,7 @@ async fn refresh_token_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let get_token = Api::init(base_url, authentication);
     let test_parameters = get_token::refresh_token::RequestParameters {
-        grant_type: String::from("some_awesome_grant_type"),
-        client_id: String::from("some_awesome_client_id"),
+        client_id: Some(String::from("some_awesome_client_id")),
         client_secret: None,
         refresh_token: String::from("some_awesome_refresh_token"),
         scope: None,
@@ -184,7 +179,7 @@ async fn token_exchange_send_request() {
     let mock = mock("POST", "/oauth/token")
         .match_header("content-type", "application/x-www-form-urlencoded")
         .match_body(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Atoken-exchange&\
             subject_token=some_awesome_subject_token&\
             subject_token_type=some_awesome_subject_token_type&\
             client_id=some_awesome_client_id",
@@ -194,10 +189,9 @@ async fn token_exchange_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let get_token = Api::init(base_url, authentication);
     let test_parameters = get_token::token_exchange_for_native_social::RequestParameters {
-        grant_type: String::from("some_awesome_grant_type"),
         subject_token: String::from("some_awesome_subject_token"),
         subject_token_type: String::from("some_awesome_subject_token_type"),
-        client_id: String::from("some_awesome_client_id"),
+        client_id: Some(String::from("some_awesome_client_id")),
         audience: None,
         scope: None,
         auth0_forwarded_for: None,
@@ -210,3 +204,53 @@ async fn token_exchange_send_request() {
     assert!(mock.matched());
     assert_eq!(test_response.unwrap().status(), reqwest::StatusCode::OK);
 }
+
+#[tokio::test]
+async fn custom_token_exchange_send_request() {
+    let mock = mock("POST", "/oauth/token")
+        .match_header("content-type", "application/x-www-form-urlencoded")
+        .match_body(
+            "client_id=some_awesome_client_id&\
+            grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Atoken-exchange&\
+            subject_token=some_awesome_legacy_token&\
+            subject_token_type=urn%3Asome-awesome-legacy-idp&\
+            audience=some_awesome_audience&\
+            client_secret=some_awesome_client_secret",
+        )
+        .with_body(
+            r#"{"access_token":"some_awesome_access_token",
+            "issued_token_type":"urn:ietf:params:oauth:token-type:access_token",
+            "token_type":"Bearer",
+            "expires_in":86400}"#,
+        )
+        .create();
+    let base_url = reqwest::Url::parse(&mockito::server_url()).unwrap();
+    let authentication = AuthenticationMethod::ClientIDClientSecret(
+        String::from("some_awesome_client_id"),
+        String::from("some_awesome_client_secret"),
+    );
+    let get_token = Api::init(base_url, authentication);
+    let test_parameters = get_token::token_exchange::RequestParameters {
+        subject_token: String::from("some_awesome_legacy_token"),
+        subject_token_type: TokenType::Other(String::from("urn:some-awesome-legacy-idp")),
+        client_id: None,
+        client_secret: None,
+        actor_token: None,
+        actor_token_type: None,
+        requested_token_type: None,
+        audience: Some(String::from("some_awesome_audience")),
+        scope: None,
+        organization: None,
+        auth0_forwarded_for: None,
+    };
+    let test_response = get_token.token_exchange(test_parameters).send().await;
+    mock.assert();
+    assert!(mock.matched());
+    let test_response = test_response
+        .unwrap()
+        .json::<get_token::token_exchange::Response>()
+        .await
+        .unwrap();
+    assert_eq!(test_response.issued_token_type, TokenType::AccessToken);
+    assert_eq!(test_response.expires_in, Some(86400));
+}
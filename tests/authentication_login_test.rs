@@ -1,14 +1,12 @@
 use auth0::authentication::login::*;
 use auth0::authentication::*;
+use auth0::models::ResponseType;
 
 #[tokio::test]
 async fn enterprise_send_request() {
     let mock = mockito::mock("GET", "/authorize")
         .match_query(mockito::Matcher::AllOf(vec![
-            mockito::Matcher::UrlEncoded(
-                "response_type".into(),
-                "some_awesome_response_type".into(),
-            ),
+            mockito::Matcher::UrlEncoded("response_type".into(), "code".into()),
             mockito::Matcher::UrlEncoded("client_id".into(), "some_awesome_client_id".into()),
             mockito::Matcher::UrlEncoded("redirect_uri".into(), "some_awesome_redirect_uri".into()),
         ]))
@@ -17,7 +15,7 @@ async fn enterprise_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let login = Api::init(base_url, authentication);
     let test_parameters = login::enterprise::RequestParameters {
-        response_type: String::from("some_awesome_response_type"),
+        response_type: ResponseType::Code,
         client_id: String::from("some_awesome_client_id"),
         connection: None,
         redirect_uri: String::from("some_awesome_redirect_uri"),
@@ -33,10 +31,7 @@ async fn enterprise_send_request() {
 async fn passive_send_request() {
     let mock = mockito::mock("GET", "/authorize")
         .match_query(mockito::Matcher::AllOf(vec![
-            mockito::Matcher::UrlEncoded(
-                "response_type".into(),
-                "some_awesome_response_type".into(),
-            ),
+            mockito::Matcher::UrlEncoded("response_type".into(), "code".into()),
             mockito::Matcher::UrlEncoded("client_id".into(), "some_awesome_client_id".into()),
             mockito::Matcher::UrlEncoded("redirect_uri".into(), "some_awesome_redirect_uri".into()),
             mockito::Matcher::UrlEncoded("state".into(), "some_awesome_state".into()),
@@ -46,7 +41,7 @@ async fn passive_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let login = Api::init(base_url, authentication);
     let test_parameters = login::passive::RequestParameters {
-        response_type: String::from("some_awesome_response_type"),
+        response_type: ResponseType::Code,
         client_id: String::from("some_awesome_client_id"),
         connection: None,
         redirect_uri: String::from("some_awesome_redirect_uri"),
@@ -63,10 +58,7 @@ async fn passive_send_request() {
 async fn social_send_request() {
     let mock = mockito::mock("GET", "/authorize")
         .match_query(mockito::Matcher::AllOf(vec![
-            mockito::Matcher::UrlEncoded(
-                "response_type".into(),
-                "some_awesome_response_type".into(),
-            ),
+            mockito::Matcher::UrlEncoded("response_type".into(), "code".into()),
             mockito::Matcher::UrlEncoded("client_id".into(), "some_awesome_client_id".into()),
             mockito::Matcher::UrlEncoded("redirect_uri".into(), "some_awesome_redirect_uri".into()),
         ]))
@@ -75,7 +67,7 @@ async fn social_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let login = Api::init(base_url, authentication);
     let test_parameters = login::social::RequestParameters {
-        response_type: String::from("some_awesome_response_type"),
+        response_type: ResponseType::Code,
         client_id: String::from("some_awesome_client_id"),
         connection: None,
         redirect_uri: String::from("some_awesome_redirect_uri"),
@@ -23,3 +23,32 @@ async fn logout_send_request() {
     assert!(mock.matched());
     assert_eq!(test_response.unwrap().status(), reqwest::StatusCode::OK);
 }
+
+#[tokio::test]
+async fn oidc_logout_send_request() {
+    let mock = mockito::mock("GET", "/oidc/logout")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("id_token_hint".into(), "some_awesome_id_token".into()),
+            mockito::Matcher::UrlEncoded(
+                "post_logout_redirect_uri".into(),
+                "some_awesome_redirect_uri".into(),
+            ),
+        ]))
+        .create();
+    let base_url = reqwest::Url::parse(&mockito::server_url()).unwrap();
+    let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+    let logout = Api::init(base_url, authentication);
+    let test_parameters = logout::OidcLogoutRequestParameters {
+        id_token_hint: Some(String::from("some_awesome_id_token")),
+        logout_hint: None,
+        client_id: None,
+        post_logout_redirect_uri: Some(String::from("some_awesome_redirect_uri")),
+        state: None,
+        federated: None,
+        ui_locales: None,
+    };
+    let test_response = logout.oidc_logout(test_parameters).send().await;
+    mock.assert();
+    assert!(mock.matched());
+    assert_eq!(test_response.unwrap().status(), reqwest::StatusCode::OK);
+}
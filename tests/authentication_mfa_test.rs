@@ -1,5 +1,6 @@
 use auth0::authentication::mfa::*;
 use auth0::authentication::*;
+use auth0::models::AuthenticatorType;
 use mockito::mock;
 
 #[tokio::test]
@@ -17,7 +18,7 @@ async fn challenge_send_request() {
     let mfa = Api::init(base_url, authentication);
     let test_parameters = mfa::challenge_request::RequestParameters {
         mfa_token: String::from("some_awesome_mfa_token"),
-        client_id: String::from("some_awesome_client_id"),
+        client_id: Some(String::from("some_awesome_client_id")),
         client_secret: None,
         challenge_type: None,
         oob_channel: None,
@@ -34,7 +35,7 @@ async fn one_time_password_send_request() {
     let mock = mock("POST", "/oauth/token")
         .match_header("content-type", "application/x-www-form-urlencoded")
         .match_body(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=http%3A%2F%2Fauth0.com%2Foauth%2Fgrant-type%2Fmfa-otp&\
             client_id=some_awesome_client_id&\
             mfa_token=some_awesome_mfa_token&\
             otp=some_awesome_otp",
@@ -44,8 +45,7 @@ async fn one_time_password_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let mfa = Api::init(base_url, authentication);
     let test_parameters = mfa::one_time_password::RequestParameters {
-        grant_type: String::from("some_awesome_grant_type"),
-        client_id: String::from("some_awesome_client_id"),
+        client_id: Some(String::from("some_awesome_client_id")),
         client_secret: None,
         mfa_token: String::from("some_awesome_mfa_token"),
         otp: String::from("some_awesome_otp"),
@@ -61,7 +61,7 @@ async fn out_of_band_send_request() {
     let mock = mock("POST", "/oauth/token")
         .match_header("content-type", "application/x-www-form-urlencoded")
         .match_body(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=http%3A%2F%2Fauth0.com%2Foauth%2Fgrant-type%2Fmfa-oob&\
             client_id=some_awesome_client_id&\
             mfa_token=some_awesome_mfa_token&\
             oob_code=some_awesome_oob_code",
@@ -71,8 +71,7 @@ async fn out_of_band_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let mfa = Api::init(base_url, authentication);
     let test_parameters = mfa::out_of_band::RequestParameters {
-        grant_type: String::from("some_awesome_grant_type"),
-        client_id: String::from("some_awesome_client_id"),
+        client_id: Some(String::from("some_awesome_client_id")),
         client_secret: None,
         mfa_token: String::from("some_awesome_mfa_token"),
         oob_code: String::from("some_awesome_oob_code"),
@@ -89,7 +88,7 @@ async fn recovery_code_send_request() {
     let mock = mock("POST", "/oauth/token")
         .match_header("content-type", "application/x-www-form-urlencoded")
         .match_body(
-            "grant_type=some_awesome_grant_type&\
+            "grant_type=http%3A%2F%2Fauth0.com%2Foauth%2Fgrant-type%2Fmfa-recovery-code&\
             client_id=some_awesome_client_id&\
             mfa_token=some_awesome_mfa_token&\
             recovery_code=some_awesome_mfa_token",
@@ -99,8 +98,7 @@ async fn recovery_code_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let mfa = Api::init(base_url, authentication);
     let test_parameters = mfa::recovery_code::RequestParameters {
-        grant_type: String::from("some_awesome_grant_type"),
-        client_id: String::from("some_awesome_client_id"),
+        client_id: Some(String::from("some_awesome_client_id")),
         client_secret: None,
         mfa_token: String::from("some_awesome_mfa_token"),
         recovery_code: String::from("some_awesome_mfa_token"),
@@ -117,7 +115,7 @@ async fn add_authenticator_send_request() {
         .match_header("content-type", "application/json")
         .match_body(mockito::Matcher::JsonString(
             r#"{"client_id": "some_awesome_client_id",
-            "authenticator_types": "some_awesome_authenticator_type"}"#
+            "authenticator_types": ["otp"]}"#
                 .to_string(),
         ))
         .create();
@@ -125,10 +123,10 @@ async fn add_authenticator_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let mfa = Api::init(base_url, authentication);
     let test_parameters = mfa::add_authenticator::RequestParameters {
-        client_id: String::from("some_awesome_client_id"),
+        client_id: Some(String::from("some_awesome_client_id")),
         client_secret: None,
-        authenticator_types: String::from("some_awesome_authenticator_type"),
-        oob_channel: None,
+        authenticator_types: vec![AuthenticatorType::Otp],
+        oob_channels: None,
         phone_number: None,
     };
     let test_response = mfa.add_authenticator(test_parameters).send().await;
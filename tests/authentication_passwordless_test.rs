@@ -17,8 +17,8 @@ async fn passwordless_start_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let passwordless = Api::init(base_url, authentication);
     let test_parameters = passwordless::get_code_or_link::RequestParameters {
-        client_id: String::from("some_awesome_client_id"),
-        client_secret: String::from("some_awesome_client_secret"),
+        client_id: Some(String::from("some_awesome_client_id")),
+        client_secret: Some(String::from("some_awesome_client_secret")),
         connection: String::from("some_awesome_connection"),
         email: Some(String::from("tester@awesome.com")),
         phone_number: None,
@@ -39,7 +39,7 @@ async fn passwordless_login_send_request() {
     let mock = mockito::mock("POST", "/oauth/token")
         .with_header("content-type", "application/json")
         .match_body(mockito::Matcher::JsonString(
-            r#"{"grant_type": "some_awesome_grant_type",
+            r#"{"grant_type": "http://auth0.com/oauth/grant-type/passwordless/otp",
             "client_id": "some_awesome_client_id",
             "client_secret": "some_awesome_client_secret",
             "username": "some_awesome_username",
@@ -52,9 +52,8 @@ async fn passwordless_login_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let passwordless = Api::init(base_url, authentication);
     let test_parameters = passwordless::authenticate_user::RequestParameters {
-        grant_type: String::from("some_awesome_grant_type"),
-        client_id: String::from("some_awesome_client_id"),
-        client_secret: String::from("some_awesome_client_secret"),
+        client_id: Some(String::from("some_awesome_client_id")),
+        client_secret: Some(String::from("some_awesome_client_secret")),
         username: String::from("some_awesome_username"),
         realm: String::from("some_awesome_realm"),
         otp: String::from("some_awesome_otp"),
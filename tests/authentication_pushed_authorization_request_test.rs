@@ -0,0 +1,50 @@
+use auth0::authentication::pushed_authorization_request::*;
+use auth0::authentication::*;
+use auth0::models::ResponseType;
+use mockito::mock;
+
+#[tokio::test]
+async fn pushed_authorization_request_send_request() {
+    let mock = mock("POST", "/oauth/par")
+        .match_header("content-type", "application/x-www-form-urlencoded")
+        .match_body(
+            "client_id=some_awesome_client_id&\
+            client_secret=some_awesome_client_secret&\
+            response_type=code&\
+            redirect_uri=some_awesome_redirect_uri&\
+            state=some_awesome_state",
+        )
+        .with_status(201)
+        .with_body(
+            r#"{"request_uri":"urn:ietf:params:oauth:request_uri:some_awesome_id",
+            "expires_in":30}"#,
+        )
+        .create();
+    let base_url = reqwest::Url::parse(&mockito::server_url()).unwrap();
+    let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+    let pushed_authorization_request = Api::init(base_url, authentication);
+    let test_parameters = pushed_authorization_request::RequestParameters {
+        client_id: Some(String::from("some_awesome_client_id")),
+        client_secret: Some(String::from("some_awesome_client_secret")),
+        response_type: ResponseType::Code,
+        redirect_uri: Some(String::from("some_awesome_redirect_uri")),
+        audience: None,
+        scope: None,
+        state: Some(String::from("some_awesome_state")),
+        nonce: None,
+        connection: None,
+        organization: None,
+        prompt: None,
+        code_challenge_method: None,
+        code_challenge: None,
+    };
+    let test_response = pushed_authorization_request
+        .pushed_authorization_request(test_parameters)
+        .request
+        .send()
+        .await;
+    mock.assert();
+    assert!(mock.matched());
+    let test_response = test_response.unwrap().json::<Response>().await.unwrap();
+    assert_eq!(test_response.expires_in, 30);
+}
@@ -17,7 +17,7 @@ async fn revoke_refresh_token_send_request() {
     let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
     let revoke_refresh_token = Api::init(base_url, authentication);
     let test_parameters = revoke_refresh_token::RequestParameters {
-        client_id: String::from("some_awesome_client_id"),
+        client_id: Some(String::from("some_awesome_client_id")),
         client_secret: Some(String::from("some_awesome_client_secret")),
         token: String::from("some_awesome_token"),
     };
@@ -0,0 +1,59 @@
+use auth0::authentication::user_profile::*;
+use auth0::authentication::*;
+use auth0::error::Error;
+use auth0::retry::RetryPolicy;
+use mockito::mock;
+
+#[tokio::test]
+async fn send_retries_too_many_requests() {
+    let throttled = mock("GET", "/userinfo")
+        .with_status(429)
+        .with_header("retry-after", "0")
+        .with_header("x-ratelimit-limit", "10")
+        .with_header("x-ratelimit-remaining", "0")
+        .with_header("x-ratelimit-reset", "0")
+        .expect(1)
+        .create();
+    let ok = mock("GET", "/userinfo").with_status(200).create();
+    let base_url = reqwest::Url::parse(&mockito::server_url()).unwrap();
+    let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+    let user_profile = Api::init(base_url, authentication);
+    let test_parameters = RequestParameters {
+        access_token: String::from("some_awesome_access_token"),
+    };
+    let test_response = user_profile
+        .send(user_profile.user_info(test_parameters))
+        .await;
+    throttled.assert();
+    ok.assert();
+    assert_eq!(test_response.unwrap().status(), reqwest::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn send_gives_up_after_max_attempts() {
+    let throttled = mock("GET", "/userinfo?attempt=max")
+        .with_status(429)
+        .with_header("retry-after", "0")
+        .expect(2)
+        .create();
+    let base_url = reqwest::Url::parse(&mockito::server_url()).unwrap();
+    let authentication = AuthenticationMethod::OAuth2Token(String::from("some_awesome_token"));
+    let user_profile = Api::init(base_url, authentication).with_retry_policy(RetryPolicy {
+        max_attempts: 2,
+        ..Default::default()
+    });
+    let test_parameters = RequestParameters {
+        access_token: String::from("some_awesome_access_token"),
+    };
+    let request = user_profile
+        .user_info(test_parameters)
+        .query(&[("attempt", "max")]);
+    let test_response = user_profile.send(request).await;
+    throttled.assert();
+    match test_response {
+        Err(Error::AuthenticationError(error)) => {
+            assert_eq!(error.status(), Some(reqwest::StatusCode::TOO_MANY_REQUESTS));
+        }
+        _ => panic!("expected an authentication error"),
+    }
+}